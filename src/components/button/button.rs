@@ -1,14 +1,18 @@
-use css_in_rust::Style;
+use std::rc::Rc;
 use yew::{
   html, Component, ShouldRender, Html, ComponentLink,
   Properties, Children, Callback, MouseEvent,
-  Classes,
+  Classes, NodeRef,
 };
-use yew::virtual_dom::{VNode, VList};
-use crate::theme::{Theme, fade};
+use yew::virtual_dom::{VNode, VList, VComp};
+use crate::theme::{Theme, Density, StyleOverride, is_valid_color_name};
+use crate::components::style_cache::{get_style, StyleScope, ComponentStyle};
+use crate::components::button_icon::{ButtonIcon, ButtonIconProps, ButtonIconPosition, ButtonIconSize};
+use crate::components::spinner::Spinner;
 
 pub enum Msg {
   Click(MouseEvent),
+  SetTheme(Rc<Theme>),
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -33,6 +37,11 @@ pub enum ButtonColor {
   Primary,
   Secondary,
   Error,
+  Success,
+  Warning,
+  Info,
+  /// A color registered with `Palette::add_color`.
+  Custom(String),
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -43,8 +52,10 @@ pub enum ButtonType {
 }
 
 pub struct Button {
-  style: Style,
+  style: ComponentStyle,
   props: Props,
+  /// Inline variables of a custom color, checked once per color.
+  color_style: Option<String>,
   link: ComponentLink<Self>,
 }
 
@@ -60,8 +71,9 @@ pub struct Props {
     pub variant: ButtonVariant,
     #[prop_or(ButtonColor::Primary)]
     pub color: ButtonColor,
-    #[prop_or(ButtonSize::Medium)]
-    pub size: ButtonSize,
+    /// Follows the theme density when not set.
+    #[prop_or_default]
+    pub size: Option<ButtonSize>,
     #[prop_or(false)]
     pub fullwidth: bool,
     #[prop_or(false)]
@@ -70,21 +82,88 @@ pub struct Props {
     pub disabled: bool,
     #[prop_or(ButtonType::Button)]
     pub button_type: ButtonType,
+    #[prop_or_default]
+    pub sx: Option<StyleOverride>,
+    /// Rendered in a `ButtonIcon` sized to the button, before the children.
+    #[prop_or_default]
+    pub start_icon: Option<Html>,
+    /// Rendered in a `ButtonIcon` sized to the button, after the children.
+    #[prop_or_default]
+    pub end_icon: Option<Html>,
+    /// Renders the button as an `<a>` to this URL.
+    #[prop_or_default]
+    pub href: Option<String>,
+    #[prop_or_default]
+    pub target: Option<String>,
+    /// Defaults to `noopener noreferrer` for `target="_blank"` links.
+    #[prop_or_default]
+    pub rel: Option<String>,
+    /// Client side navigation for link buttons, e.g. pushing the route to
+    /// yew-router's `RouteAgentDispatcher`. Plain left clicks call it with
+    /// `href` instead of loading the page; middle and modified clicks keep
+    /// the browser behavior, like opening the link in a new tab.
+    #[prop_or_default]
+    pub onnavigate: Option<Callback<String>>,
 }
 
-fn get_style_class(variant: &ButtonVariant, color: &ButtonColor) -> String {
+fn get_variant_class(variant: &ButtonVariant) -> String {
   let v = match variant {
       ButtonVariant::Contained => { "contained" }
       ButtonVariant::Outlined => { "outlined" }
       ButtonVariant::Inline => { "inline" }
   };
+
+  String::from(v)
+}
+
+pub(crate) fn get_color_class(color: &ButtonColor) -> String {
   let c = match color {
     ButtonColor::Primary => { "primary" }
     ButtonColor::Secondary => { "secondary" }
     ButtonColor::Error => { "error" }
+    ButtonColor::Success => { "success" }
+    ButtonColor::Warning => { "warning" }
+    ButtonColor::Info => { "info" }
+    ButtonColor::Custom(_) => { "custom" }
   };
 
-  format!("{c}-{v}", c = c, v = v)
+  format!("color-{}", c)
+}
+
+/// Custom colors have no class of their own, their variables are set inline.
+/// Invalid names are left out, see `is_valid_color_name`.
+pub(crate) fn get_color_style(color: &ButtonColor) -> Option<String> {
+  match color {
+    ButtonColor::Custom(name) if is_valid_color_name(name) => {
+      Some(
+        ["main", "dark", "main-fade", "contrast-text", "text"].iter()
+          .map(|v| format!("--dao-button-color-{v}: var(--dao-palette-{n}-{v});", v = v, n = name))
+          .collect::<Vec<String>>()
+          .join(" ")
+      )
+    }
+    ButtonColor::Custom(name) => {
+      log::error!("invalid custom color name \"{}\"", name);
+      None
+    }
+    _ => { None }
+  }
+}
+
+fn get_density_size(density: Density) -> ButtonSize {
+  match density {
+    Density::Compact => { ButtonSize::Small }
+    Density::Comfortable => { ButtonSize::Medium }
+    Density::Spacious => { ButtonSize::Large }
+  }
+}
+
+fn get_icon_size(size: &ButtonSize) -> ButtonIconSize {
+  match size {
+    ButtonSize::Small => { ButtonIconSize::Small }
+    ButtonSize::Medium => { ButtonIconSize::Medium }
+    ButtonSize::Large => { ButtonIconSize::Large }
+  }
 }
 
 fn get_size_class(size: &ButtonSize) -> String {
@@ -97,34 +176,39 @@ fn get_size_class(size: &ButtonSize) -> String {
   format!("size-{}", s)
 }
 
+/// Whether a click on a link button stays in the current tab: a plain left
+/// click, on a link without another target.
+fn is_same_tab_click(target: Option<&str>, button: i16, modified: bool) -> bool {
+  matches!(target, None | Some("_self")) && button == 0 && !modified
+}
+
+fn get_rel(rel: &Option<String>, target: Option<&str>) -> Option<String> {
+  match (rel, target) {
+    (Some(rel), _) => { Some(rel.clone()) }
+    (None, Some("_blank")) => { Some(String::from("noopener noreferrer")) }
+    _ => { None }
+  }
+}
+
+/// Variables of the built-in colors, shared with `IconButton`.
+pub(crate) const COLOR_CSS: &str = include_str!("button_colors.scss");
+
+pub(crate) fn component_css() -> String {
+  format!("{}\n{}", include_str!("button.scss"), COLOR_CSS)
+}
+
+fn create_style(theme: &Theme) -> StyleScope {
+  get_style("button", theme, &theme.components.button.style_overrides, component_css)
+}
+
 impl Component for Button {
   type Message = Msg;
   type Properties = Props;
 
   fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-    let theme = Theme::default();
-    let style = Style::create(
-      String::from("button"),
-      include_str!("button.scss")
-        .replace("$button_border_radius", &theme.components.button.border_radius)
-        .replace("$button_small_size_padding", &theme.components.button.small_size_padding)
-        .replace("$button_large_size_padding", &theme.components.button.large_size_padding)
-        .replace("$palette_primary_main_fade", &fade(theme.palette.primary.main.clone()))
-        .replace("$palette_primary_main", &theme.palette.primary.main)
-        .replace("$palette_primary_dark", &theme.palette.primary.dark)
-        .replace("$palette_secondary_main_fade", &fade(theme.palette.secondary.main.clone()))
-        .replace("$palette_secondary_main", &theme.palette.secondary.main)
-        .replace("$palette_secondary_dark", &theme.palette.secondary.dark)
-        .replace("$button_large_height", &theme.components.button.large_height)
-        .replace("$button_small_height", &theme.components.button.small_height)
-        .replace("$palette_error_main_fade", &fade(theme.palette.error.main.clone()))
-        .replace("$palette_error_main", &theme.palette.error.main)
-        .replace("$palette_error_dark", &theme.palette.error.dark),
-    )
-    .expect("An error occured while creating the style");
-
     Self {
-      style,
+      style: ComponentStyle::new(&link, link.callback(Msg::SetTheme), "button", create_style, &props.sx),
+      color_style: get_color_style(&props.color),
       props,
       link,
     }
@@ -133,38 +217,68 @@ impl Component for Button {
   fn update(&mut self, msg: Self::Message) -> ShouldRender {
     match msg {
       Msg::Click(event) => {
-        println!("Click");
+        if self.is_inactive() {
+          event.prevent_default();
+          return false;
+        }
+
+        if let (Some(href), Some(onnavigate)) = (&self.props.href, &self.props.onnavigate) {
+          if self.is_same_tab_click(&event) {
+            event.prevent_default();
+            onnavigate.emit(href.clone());
+          }
+        }
+
         self.props.onclick.emit(event);
+        false
+      }
+      Msg::SetTheme(theme) => {
+        self.style.set_theme(theme, &self.props.sx);
+        true
       }
     }
-    false
   }
 
   fn change(&mut self, props: Self::Properties) -> ShouldRender {
-    if self.props != props {
-      self.props = props;
-      
-      true
-    } else {
-        false
+    if self.props.color != props.color {
+      self.color_style = get_color_style(&props.color);
     }
+
+    self.style.change(&mut self.props, props, |props| &mut props.sx)
   }
 
   fn view(&self) -> Html {
     let onclick = self.link.callback(Msg::Click);
     let class = self.format_classes();
 
+    if let Some(href) = &self.props.href {
+      let inactive = self.is_inactive();
+
+      return html! {
+        <a
+          class=class
+          style=self.color_style.clone()
+          onclick=onclick
+          href=if inactive { None } else { Some(href.clone()) }
+          target=self.props.target.clone()
+          rel=get_rel(&self.props.rel, self.props.target.as_deref())
+          aria-disabled=if inactive { Some("true") } else { None }
+          tabindex=if inactive { Some("-1") } else { None }
+        >
+          {self.render_content()}
+        </a>
+      };
+    }
+
     html! {
       <button
         class=class
+        style=self.color_style.clone()
         onclick=onclick
-        disabled=self.props.disabled || self.props.loading
+        disabled=self.is_inactive()
         type=self.get_type()
       >
-        <div class="content">
-          {self.props.children.clone()}
-        </div>
-        {self.render_spinner()}
+        {self.render_content()}
       </button>
     }
   }
@@ -172,45 +286,110 @@ impl Component for Button {
 
 impl Button {
   fn format_classes(&self) -> Classes {
-    let mut classes = Classes::from(self.style.clone().to_string());
+    let mut classes = self.style.classes(&self.props.class);
 
-    classes.push(self.props.class.clone());
-    classes.push(get_style_class(&self.props.variant, &self.props.color));
-    classes.push(get_size_class(&self.props.size));
+    classes.push(self.style.modifier(&get_variant_class(&self.props.variant)));
+    classes.push(self.style.modifier(&get_color_class(&self.props.color)));
+    classes.push(self.style.modifier(&get_size_class(&self.get_size())));
 
     if self.props.disabled {
-      classes.push("disabled");
+      classes.push(self.style.modifier("disabled"));
     }
     if self.props.loading {
-      classes.push("loading");
+      classes.push(self.style.modifier("loading"));
     }
     if self.props.fullwidth {
-      classes.push("fullwidth");
+      classes.push(self.style.modifier("fullwidth"));
     }
 
     classes
   }
 
+  fn render_content(&self) -> Html {
+    html! {
+      <>
+        <div class=self.style.element("content")>
+          {self.render_icon(&self.props.start_icon, ButtonIconPosition::Start)}
+          {self.props.children.clone()}
+          {self.render_icon(&self.props.end_icon, ButtonIconPosition::End)}
+        </div>
+        {self.render_spinner()}
+      </>
+    }
+  }
+
+  /// Icons live in `.content`, so they keep their space and hide with the
+  /// label while loading.
+  fn render_icon(&self, icon: &Option<Html>, position: ButtonIconPosition) -> Html {
+    if let Some(icon) = icon {
+      let class = match position {
+        ButtonIconPosition::Start => { "start-icon" }
+        ButtonIconPosition::End => { "end-icon" }
+      };
+      let props = ButtonIconProps {
+        class: self.style.element(class),
+        children: Children::new(vec![icon.clone()]),
+        size: Some(get_icon_size(&self.get_size())),
+        position,
+        sx: None,
+      };
+
+      VComp::new::<ButtonIcon>(props, NodeRef::default(), None).into()
+    } else {
+      VNode::from(VList::new())
+    }
+  }
+
   fn render_spinner(&self) -> Html {
     if self.props.loading {
       html! {
-        <svg
-          class="spinner"
-          viewBox="0 0 50 50"
-          data-ui-element="button-spinner"
-        >
-          <circle class="path" cx="25" cy="25" r="20" fill="none" stroke-width="5"></circle>
-        </svg>
+        <Spinner class=self.style.element("spinner") ui_element="button-spinner" />
       }
     } else {
       VNode::from(VList::new())
     }
   }
 
+  fn get_size(&self) -> ButtonSize {
+    self.props.size.clone().unwrap_or_else(|| get_density_size(self.style.theme().density))
+  }
+
+  fn is_inactive(&self) -> bool {
+    self.props.disabled || self.props.loading
+  }
+
+  fn is_same_tab_click(&self, event: &MouseEvent) -> bool {
+    let modified = event.ctrl_key() || event.meta_key() || event.shift_key() || event.alt_key();
+
+    is_same_tab_click(self.props.target.as_deref(), event.button(), modified)
+  }
+
   fn get_type(&self) -> String {
     match self.props.button_type {
       ButtonType::Button => { String::from("button") }
       ButtonType::Submit => { String::from("submit") }
     }
   }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+  use super::{get_rel, is_same_tab_click};
+
+  #[test]
+  fn blank_targets_default_to_noopener() {
+    assert_eq!(get_rel(&None, Some("_blank")), Some(String::from("noopener noreferrer")));
+    assert_eq!(get_rel(&Some(String::from("opener")), Some("_blank")), Some(String::from("opener")));
+    assert_eq!(get_rel(&None, Some("_self")), None);
+    assert_eq!(get_rel(&None, None), None);
+  }
+
+  #[test]
+  fn only_plain_left_clicks_stay_in_the_tab() {
+    assert!(is_same_tab_click(None, 0, false));
+    assert!(is_same_tab_click(Some("_self"), 0, false));
+    // Middle clicks and modifier keys open new tabs or windows.
+    assert!(!is_same_tab_click(None, 1, false));
+    assert!(!is_same_tab_click(None, 0, true));
+    assert!(!is_same_tab_click(Some("_blank"), 0, false));
+  }
+}
@@ -3,4 +3,8 @@ mod button;
 pub use button::Button;
 pub use button::ButtonVariant;
 pub use button::ButtonColor;
-pub use button::ButtonSize;
\ No newline at end of file
+pub use button::ButtonSize;
+
+pub(crate) use button::component_css;
+pub(crate) use button::Props as ButtonProps;
+pub(crate) use button::{get_color_class, get_color_style, COLOR_CSS};
\ No newline at end of file
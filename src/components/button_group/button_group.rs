@@ -0,0 +1,241 @@
+use std::rc::Rc;
+use yew::{
+  html, Component, ShouldRender, Html, ComponentLink, Properties, Classes, NodeRef,
+  html::{
+    ChildrenRenderer,
+  },
+  virtual_dom::{VComp, VChild},
+};
+use crate::theme::{Theme, StyleOverride};
+use crate::components::style_cache::{get_style, StyleScope, ComponentStyle};
+use crate::components::button::{Button, ButtonProps, ButtonVariant, ButtonColor, ButtonSize};
+
+#[derive(Clone, PartialEq)]
+pub enum Variants {
+  Button(<Button as Component>::Properties),
+}
+
+impl From<ButtonProps> for Variants {
+  fn from(props: ButtonProps) -> Self {
+      Variants::Button(props)
+  }
+}
+
+#[derive(PartialEq, Clone)]
+pub struct ChildVariant {
+  props: Variants,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum ButtonGroupOrientation {
+  Horizontal,
+  Vertical,
+}
+
+pub enum Msg {
+  SetTheme(Rc<Theme>),
+}
+
+pub struct ButtonGroup {
+  style: ComponentStyle,
+  props: Props,
+}
+
+#[derive(Properties, Clone, PartialEq, Debug)]
+pub struct Props {
+    #[prop_or_default]
+    pub class: String,
+    #[prop_or_default]
+    pub children: ChildrenRenderer<ChildVariant>,
+    /// Buttons keep their own variant when not set.
+    #[prop_or_default]
+    pub variant: Option<ButtonVariant>,
+    /// Buttons keep their own color when not set.
+    #[prop_or_default]
+    pub color: Option<ButtonColor>,
+    /// Buttons keep their own size, which follows the theme density by
+    /// default, when not set.
+    #[prop_or_default]
+    pub size: Option<ButtonSize>,
+    /// Disables every button, buttons can still be disabled one by one.
+    #[prop_or(false)]
+    pub disabled: bool,
+    #[prop_or(ButtonGroupOrientation::Horizontal)]
+    pub orientation: ButtonGroupOrientation,
+    #[prop_or_default]
+    pub sx: Option<StyleOverride>,
+}
+
+impl<CHILD> From<VChild<CHILD>> for ChildVariant
+where
+    CHILD: Component,
+    CHILD::Properties: Into<Variants>,
+{
+    fn from(vchild: VChild<CHILD>) -> Self {
+        Self {
+            props: vchild.props.into(),
+        }
+    }
+}
+
+impl From<ChildVariant> for Html {
+  fn from(variant: ChildVariant) -> Html {
+      match variant.props {
+        Variants::Button(props) => VComp::new::<Button>(props, NodeRef::default(), None).into(),
+      }
+  }
+}
+
+fn get_orientation_class(orientation: &ButtonGroupOrientation) -> String {
+  let o = match orientation {
+    ButtonGroupOrientation::Horizontal => { "horizontal" }
+    ButtonGroupOrientation::Vertical => { "vertical" }
+  };
+
+  format!("orientation-{}", o)
+}
+
+/// Applies the props the group shares with its buttons. Buttons keep their
+/// own variant, color and size unless the group sets them.
+fn apply_group_props(group: &Props, button: &mut ButtonProps) {
+  if let Some(variant) = &group.variant {
+    button.variant = variant.clone();
+  }
+  if let Some(color) = &group.color {
+    button.color = color.clone();
+  }
+  button.disabled = group.disabled || button.disabled;
+
+  if group.size.is_some() {
+    button.size = group.size.clone();
+  }
+}
+
+pub(crate) fn component_css() -> String {
+  String::from(include_str!("button_group.scss"))
+}
+
+fn create_style(theme: &Theme) -> StyleScope {
+  get_style("button_group", theme, &theme.components.button_group.style_overrides, component_css)
+}
+
+impl Component for ButtonGroup {
+  type Message = Msg;
+  type Properties = Props;
+
+  fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+    Self {
+      style: ComponentStyle::new(&link, link.callback(Msg::SetTheme), "button_group", create_style, &props.sx),
+      props,
+    }
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::SetTheme(theme) => {
+        self.style.set_theme(theme, &self.props.sx);
+        true
+      }
+    }
+  }
+
+  fn change(&mut self, props: Self::Properties) -> ShouldRender {
+    self.style.change(&mut self.props, props, |props| &mut props.sx)
+  }
+
+  fn view(&self) -> Html {
+    html! {
+      <div
+        class=self.format_classes()
+        role="group"
+      >
+        {
+          self.props.children.iter()
+            .map(|mut c| {
+              let Variants::Button(ref mut props) = c.props;
+              apply_group_props(&self.props, props);
+              c
+            })
+            .collect::<Html>()
+        }
+      </div>
+    }
+  }
+}
+
+impl ButtonGroup {
+  fn format_classes(&self) -> Classes {
+    let mut classes = self.style.classes(&self.props.class);
+
+    classes.push(self.style.modifier(&get_orientation_class(&self.props.orientation)));
+
+    classes
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use yew::Properties;
+  use super::{apply_group_props, Props};
+  use crate::components::button::{ButtonProps, ButtonVariant, ButtonColor, ButtonSize};
+
+  #[test]
+  fn pushes_variant_color_and_disabled_down() {
+    let group = Props::builder()
+      .variant(Some(ButtonVariant::Outlined))
+      .color(Some(ButtonColor::Secondary))
+      .disabled(true)
+      .build();
+    let mut button = ButtonProps::builder()
+      .variant(ButtonVariant::Inline)
+      .color(ButtonColor::Error)
+      .build();
+
+    apply_group_props(&group, &mut button);
+
+    assert_eq!(button.variant, ButtonVariant::Outlined);
+    assert_eq!(button.color, ButtonColor::Secondary);
+    assert!(button.disabled);
+  }
+
+  #[test]
+  fn keeps_disabled_buttons() {
+    let group = Props::builder().build();
+    let mut button = ButtonProps::builder().disabled(true).build();
+
+    apply_group_props(&group, &mut button);
+
+    assert!(button.disabled);
+  }
+
+  #[test]
+  fn pushes_variant_and_color_down_only_when_set() {
+    let mut button = ButtonProps::builder()
+      .variant(ButtonVariant::Outlined)
+      .color(ButtonColor::Error)
+      .build();
+
+    apply_group_props(&Props::builder().build(), &mut button);
+    assert_eq!(button.variant, ButtonVariant::Outlined);
+    assert_eq!(button.color, ButtonColor::Error);
+
+    apply_group_props(&Props::builder().variant(Some(ButtonVariant::Inline)).build(), &mut button);
+    assert_eq!(button.variant, ButtonVariant::Inline);
+    assert_eq!(button.color, ButtonColor::Error);
+
+    apply_group_props(&Props::builder().color(Some(ButtonColor::Success)).build(), &mut button);
+    assert_eq!(button.variant, ButtonVariant::Inline);
+    assert_eq!(button.color, ButtonColor::Success);
+  }
+
+  #[test]
+  fn pushes_size_down_only_when_set() {
+    let mut button = ButtonProps::builder().size(Some(ButtonSize::Small)).build();
+
+    apply_group_props(&Props::builder().build(), &mut button);
+    assert_eq!(button.size, Some(ButtonSize::Small));
+
+    apply_group_props(&Props::builder().size(Some(ButtonSize::Large)).build(), &mut button);
+    assert_eq!(button.size, Some(ButtonSize::Large));
+  }
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+mod button_group;
+
+pub use button_group::ButtonGroup;
+pub use button_group::ButtonGroupOrientation;
+
+pub(crate) use button_group::component_css;
\ No newline at end of file
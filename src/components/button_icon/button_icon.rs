@@ -1,9 +1,14 @@
-use css_in_rust::Style;
+use std::rc::Rc;
 use yew::{
   html, Component, ShouldRender, Html, ComponentLink,
   Properties, Children, Classes,
 };
-use crate::theme::{Theme};
+use crate::theme::{Theme, Density, StyleOverride};
+use crate::components::style_cache::{get_style, StyleScope, ComponentStyle};
+
+pub enum Msg {
+  SetTheme(Rc<Theme>),
+}
 
 #[derive(Clone, PartialEq, Debug)]
 #[allow(dead_code)]
@@ -21,7 +26,7 @@ pub enum ButtonIconPosition {
 }
 
 pub struct ButtonIcon {
-  style: Style,
+  style: ComponentStyle,
   props: Props,
 }
 
@@ -31,10 +36,21 @@ pub struct Props {
     pub class: String,
     #[prop_or_default]
     pub children: Children,
-    #[prop_or(ButtonIconSize::Medium)]
-    pub size: ButtonIconSize,
+    /// Follows the theme density when not set.
+    #[prop_or_default]
+    pub size: Option<ButtonIconSize>,
     #[prop_or(ButtonIconPosition::Start)]
     pub position: ButtonIconPosition,
+    #[prop_or_default]
+    pub sx: Option<StyleOverride>,
+}
+
+pub(crate) fn get_density_size(density: Density) -> ButtonIconSize {
+  match density {
+    Density::Compact => { ButtonIconSize::Small }
+    Density::Comfortable => { ButtonIconSize::Medium }
+    Density::Spacious => { ButtonIconSize::Large }
+  }
 }
 
 fn get_size_class(size: &ButtonIconSize) -> String {
@@ -56,38 +72,36 @@ fn get_position_class(size: &ButtonIconPosition) -> String {
   format!("position-{}", s)
 }
 
+pub(crate) fn component_css() -> String {
+  String::from(include_str!("button_icon.scss"))
+}
+
+fn create_style(theme: &Theme) -> StyleScope {
+  get_style("button_icon", theme, &theme.components.button_icon.style_overrides, component_css)
+}
+
 impl Component for ButtonIcon {
-  type Message = ();
+  type Message = Msg;
   type Properties = Props;
 
-  fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
-    let theme = Theme::default();
-    let style = Style::create(
-      String::from("button_icon"),
-      include_str!("button_icon.scss")
-        .replace("$breakpoint_md", &theme.breakpoints.md.to_string())
-        .replace("$palette_divider", &theme.palette.divider),
-    )
-    .expect("An error occured while creating the style");
-
+  fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
     Self {
-      style,
+      style: ComponentStyle::new(&link, link.callback(Msg::SetTheme), "button_icon", create_style, &props.sx),
       props,
     }
   }
 
-  fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-    false
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::SetTheme(theme) => {
+        self.style.set_theme(theme, &self.props.sx);
+        true
+      }
+    }
   }
 
   fn change(&mut self, props: Self::Properties) -> ShouldRender {
-    if self.props != props {
-      self.props = props;
-      
-      true
-    } else {
-      false
-    }
+    self.style.change(&mut self.props, props, |props| &mut props.sx)
   }
 
   fn view(&self) -> Html {
@@ -105,12 +119,15 @@ impl Component for ButtonIcon {
 
 impl ButtonIcon {
   fn format_classes(&self) -> Classes {
-    let mut classes = Classes::from(self.style.clone().to_string());
+    let mut classes = self.style.classes(&self.props.class);
 
-    classes.push(self.props.class.clone());
-    classes.push(get_size_class(&self.props.size));
-    classes.push(get_position_class(&self.props.position));
+    classes.push(self.style.modifier(&get_size_class(&self.get_size())));
+    classes.push(self.style.modifier(&get_position_class(&self.props.position)));
 
     classes
   }
-}
\ No newline at end of file
+
+  fn get_size(&self) -> ButtonIconSize {
+    self.props.size.clone().unwrap_or_else(|| get_density_size(self.style.theme().density))
+  }
+}
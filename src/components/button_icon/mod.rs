@@ -2,4 +2,7 @@ mod button_icon;
 
 pub use button_icon::ButtonIcon;
 pub use button_icon::ButtonIconPosition;
-pub use button_icon::ButtonIconSize;
\ No newline at end of file
+pub use button_icon::ButtonIconSize;
+
+pub(crate) use button_icon::{component_css, get_density_size};
+pub(crate) use button_icon::Props as ButtonIconProps;
\ No newline at end of file
@@ -0,0 +1,175 @@
+//! Splits style sheets into declarations, rules and selectors for the
+//! scoping of overrides and of `ssr::extract_css`. Quoted strings and
+//! parentheses are skipped, so `content: "}"`, `url(data:…;base64,…)` and
+//! `:not(.a, .b)` stay whole.
+
+/// A top level part of a style sheet, raw text included: joined back
+/// together, the parts give the sheet again.
+#[derive(Debug, PartialEq)]
+pub enum CssItem<'a> {
+  /// Text up to and including a `;`, usually a declaration. Text left at
+  /// the end of the sheet is one as well.
+  Declaration(&'a str),
+  /// The text before a `{`, and the text between it and its `}`.
+  Rule(&'a str, &'a str),
+}
+
+/// Tracks quoted strings and parentheses, one character at a time.
+#[derive(Default)]
+struct Scanner {
+  quote: Option<char>,
+  escaped: bool,
+  parens: i32,
+}
+
+impl Scanner {
+  /// Updates the state with `c` and returns whether `c` is outside of any
+  /// quoted string.
+  fn unquoted(&mut self, c: char) -> bool {
+    if let Some(q) = self.quote {
+      if self.escaped {
+        self.escaped = false;
+      } else if c == '\\' {
+        self.escaped = true;
+      } else if c == q {
+        self.quote = None;
+      }
+      return false;
+    }
+
+    match c {
+      '"' | '\'' => {
+        self.quote = Some(c);
+        return false;
+      }
+      '(' => { self.parens += 1 }
+      ')' => { self.parens -= 1 }
+      _ => {}
+    }
+
+    true
+  }
+
+  /// Returns whether the last character is outside of any parentheses.
+  fn top_level(&self) -> bool {
+    self.parens <= 0
+  }
+}
+
+/// Splits `css` into its top level declarations and rules.
+pub fn split_items(css: &str) -> Vec<CssItem<'_>> {
+  let mut items = vec![];
+  let mut scanner = Scanner::default();
+  let mut start = 0;
+  let mut open = None;
+  let mut depth = 0;
+
+  for (i, c) in css.char_indices() {
+    if !scanner.unquoted(c) || !scanner.top_level() {
+      continue;
+    }
+
+    match c {
+      ';' if depth == 0 => {
+        items.push(CssItem::Declaration(&css[start..=i]));
+        start = i + 1;
+      }
+      '{' => {
+        if depth == 0 {
+          open = Some(i);
+        }
+        depth += 1;
+      }
+      '}' if depth > 0 => {
+        depth -= 1;
+        if let (0, Some(open)) = (depth, open) {
+          items.push(CssItem::Rule(&css[start..open], &css[open + 1..i]));
+          start = i + 1;
+        }
+      }
+      _ => {}
+    }
+  }
+
+  if start < css.len() {
+    items.push(CssItem::Declaration(&css[start..]));
+  }
+
+  items
+}
+
+/// Splits a selector list on its top level commas.
+pub fn split_selectors(selectors: &str) -> Vec<&str> {
+  let mut parts = vec![];
+  let mut scanner = Scanner::default();
+  let mut start = 0;
+
+  for (i, c) in selectors.char_indices() {
+    if scanner.unquoted(c) && scanner.top_level() && c == ',' {
+      parts.push(&selectors[start..i]);
+      start = i + 1;
+    }
+  }
+
+  parts.push(&selectors[start..]);
+  parts
+}
+
+/// Replaces every `&` of `selector` with `scope`, those in strings aside,
+/// e.g. `&[title="a&b"]`. Returns `None` when there is no `&` to replace.
+pub fn replace_parent(selector: &str, scope: &str) -> Option<String> {
+  let mut scoped = String::with_capacity(selector.len());
+  let mut scanner = Scanner::default();
+  let mut replaced = false;
+
+  for c in selector.chars() {
+    if scanner.unquoted(c) && c == '&' {
+      scoped.push_str(scope);
+      replaced = true;
+    } else {
+      scoped.push(c);
+    }
+  }
+
+  if replaced {
+    Some(scoped)
+  } else {
+    None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{split_items, split_selectors, replace_parent, CssItem};
+
+  #[test]
+  fn splits_items() {
+    assert_eq!(
+      split_items("color: red; & { content: \"}\"; } @media x { & { margin: 0; } }"),
+      [
+        CssItem::Declaration("color: red;"),
+        CssItem::Rule(" & ", " content: \"}\"; "),
+        CssItem::Rule(" @media x ", " & { margin: 0; } "),
+      ],
+    );
+    assert_eq!(
+      split_items("background: url(data:image/png;base64,AA==); margin: 0"),
+      [
+        CssItem::Declaration("background: url(data:image/png;base64,AA==);"),
+        CssItem::Declaration(" margin: 0"),
+      ],
+    );
+  }
+
+  #[test]
+  fn splits_selectors() {
+    assert_eq!(split_selectors("&:not(.a, .b), a[title=\"x,y\"]"), ["&:not(.a, .b)", " a[title=\"x,y\"]"]);
+  }
+
+  #[test]
+  fn replaces_parents() {
+    assert_eq!(replace_parent("&:not(&.x) > a", ".b"), Some(String::from(".b:not(.b.x) > a")));
+    assert_eq!(replace_parent("&[title=\"a&b\"]", ".b"), Some(String::from(".b[title=\"a&b\"]")));
+    assert_eq!(replace_parent("a[title=\"a&b\"]", ".b"), None);
+  }
+}
@@ -0,0 +1,175 @@
+use std::rc::Rc;
+use yew::{
+  html, Component, ShouldRender, Html, ComponentLink,
+  Properties, Children, Callback, MouseEvent,
+  Classes,
+};
+use yew::virtual_dom::{VNode, VList};
+use crate::theme::{Theme, StyleOverride};
+use crate::components::style_cache::{get_style, StyleScope, ComponentStyle};
+use crate::components::button::{ButtonColor, get_color_class, get_color_style, COLOR_CSS};
+use crate::components::button_icon::{ButtonIconSize, get_density_size};
+use crate::components::spinner::Spinner;
+
+pub enum Msg {
+  Click(MouseEvent),
+  SetTheme(Rc<Theme>),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[allow(dead_code)]
+pub enum IconButtonShape {
+  Circle,
+  /// Uses the corners of `Button`.
+  Square,
+}
+
+pub struct IconButton {
+  style: ComponentStyle,
+  props: Props,
+  /// Inline variables of a custom color, checked once per color.
+  color_style: Option<String>,
+  link: ComponentLink<Self>,
+}
+
+#[derive(Properties, Clone, PartialEq, Debug)]
+pub struct Props {
+    #[prop_or_default]
+    pub class: String,
+    /// The icon, usually an `<svg>`.
+    #[prop_or_default]
+    pub children: Children,
+    /// The accessible name, the button has no text of its own.
+    pub label: String,
+    #[prop_or_else(Callback::noop)]
+    pub onclick: Callback<MouseEvent>,
+    #[prop_or(ButtonColor::Primary)]
+    pub color: ButtonColor,
+    /// Size of the icon, follows the theme density when not set.
+    #[prop_or_default]
+    pub size: Option<ButtonIconSize>,
+    #[prop_or(IconButtonShape::Circle)]
+    pub shape: IconButtonShape,
+    #[prop_or(false)]
+    pub loading: bool,
+    #[prop_or(false)]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub sx: Option<StyleOverride>,
+}
+
+fn get_size_class(size: &ButtonIconSize) -> String {
+  let s = match size {
+    ButtonIconSize::Small => { "small" }
+    ButtonIconSize::Medium => { "medium" }
+    ButtonIconSize::Large => { "large" }
+  };
+
+  format!("size-{}", s)
+}
+
+fn get_shape_class(shape: &IconButtonShape) -> String {
+  let s = match shape {
+    IconButtonShape::Circle => { "circle" }
+    IconButtonShape::Square => { "square" }
+  };
+
+  format!("shape-{}", s)
+}
+
+pub(crate) fn component_css() -> String {
+  format!("{}\n{}", include_str!("icon_button.scss"), COLOR_CSS)
+}
+
+fn create_style(theme: &Theme) -> StyleScope {
+  get_style("icon_button", theme, &theme.components.icon_button.style_overrides, component_css)
+}
+
+impl Component for IconButton {
+  type Message = Msg;
+  type Properties = Props;
+
+  fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+    Self {
+      style: ComponentStyle::new(&link, link.callback(Msg::SetTheme), "icon_button", create_style, &props.sx),
+      color_style: get_color_style(&props.color),
+      props,
+      link,
+    }
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::Click(event) => {
+        self.props.onclick.emit(event);
+        false
+      }
+      Msg::SetTheme(theme) => {
+        self.style.set_theme(theme, &self.props.sx);
+        true
+      }
+    }
+  }
+
+  fn change(&mut self, props: Self::Properties) -> ShouldRender {
+    if self.props.color != props.color {
+      self.color_style = get_color_style(&props.color);
+    }
+
+    self.style.change(&mut self.props, props, |props| &mut props.sx)
+  }
+
+  fn view(&self) -> Html {
+    let onclick = self.link.callback(Msg::Click);
+    let class = self.format_classes();
+
+    html! {
+      <button
+        class=class
+        style=self.color_style.clone()
+        onclick=onclick
+        disabled=self.props.disabled || self.props.loading
+        type="button"
+        aria-label=self.props.label.clone()
+      >
+        <span class=self.style.element("icon")>
+          {self.props.children.clone()}
+        </span>
+        {self.render_spinner()}
+      </button>
+    }
+  }
+}
+
+impl IconButton {
+  fn format_classes(&self) -> Classes {
+    let mut classes = self.style.classes(&self.props.class);
+
+    classes.push(self.style.modifier(&get_color_class(&self.props.color)));
+    classes.push(self.style.modifier(&get_size_class(&self.get_size())));
+    classes.push(self.style.modifier(&get_shape_class(&self.props.shape)));
+
+    if self.props.disabled {
+      classes.push(self.style.modifier("disabled"));
+    }
+    if self.props.loading {
+      classes.push(self.style.modifier("loading"));
+    }
+
+    classes
+  }
+
+  fn render_spinner(&self) -> Html {
+    if self.props.loading {
+      html! {
+        <Spinner class=self.style.element("spinner") ui_element="icon-button-spinner" />
+      }
+    } else {
+      VNode::from(VList::new())
+    }
+  }
+
+  fn get_size(&self) -> ButtonIconSize {
+    self.props.size.clone().unwrap_or_else(|| get_density_size(self.style.theme().density))
+  }
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+mod icon_button;
+
+pub use icon_button::IconButton;
+pub use icon_button::IconButtonShape;
+
+pub(crate) use icon_button::component_css;
\ No newline at end of file
@@ -1,17 +1,19 @@
-use css_in_rust::Style;
+use std::rc::Rc;
 use yew::{
   html, Component, ShouldRender, Html, ComponentLink,
   Properties, Children, Callback, MouseEvent,
   Classes,
 };
-use crate::theme::{Theme};
+use crate::theme::{Theme, Breakpoint, StyleOverride};
+use crate::components::style_cache::{get_theme_style, StyleScope, ComponentStyle};
 
 pub enum Msg {
   Click(MouseEvent),
+  SetTheme(Rc<Theme>),
 }
 
 pub struct MenuItem {
-  style: Style,
+  style: ComponentStyle,
   props: Props,
   link: ComponentLink<Self>,
 }
@@ -26,6 +28,18 @@ pub struct Props {
     pub onclick: Callback<MouseEvent>,
     #[prop_or(false)]
     pub disabled: bool,
+    #[prop_or_default]
+    pub sx: Option<StyleOverride>,
+}
+
+pub(crate) fn component_css(theme: &Theme) -> String {
+  include_str!("menu_item.scss")
+    .replace("$up_md", &theme.breakpoints.up(Breakpoint::Md))
+    .replace("$down_md", &theme.breakpoints.down(Breakpoint::Md))
+}
+
+fn create_style(theme: &Theme) -> StyleScope {
+  get_theme_style("menu_item", theme, &theme.components.menu_item.style_overrides, component_css)
 }
 
 impl Component for MenuItem {
@@ -33,17 +47,8 @@ impl Component for MenuItem {
   type Properties = Props;
 
   fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-    let theme = Theme::default();
-    let style = Style::create(
-      String::from("menu_item"),
-      include_str!("menu_item.scss")
-        .replace("$breakpoint_md", &theme.breakpoints.md.to_string())
-        .replace("$palette_divider", &theme.palette.divider),
-    )
-    .expect("An error occured while creating the style");
-
     Self {
-      style,
+      style: ComponentStyle::new(&link, link.callback(Msg::SetTheme), "menu_item", create_style, &props.sx),
       props,
       link,
     }
@@ -53,19 +58,17 @@ impl Component for MenuItem {
     match msg {
       Msg::Click(event) => {
         self.props.onclick.emit(event);
+        false
+      }
+      Msg::SetTheme(theme) => {
+        self.style.set_theme(theme, &self.props.sx);
+        true
       }
     }
-    false
   }
 
   fn change(&mut self, props: Self::Properties) -> ShouldRender {
-    if self.props != props {
-      self.props = props;
-      
-      true
-    } else {
-      false
-    }
+    self.style.change(&mut self.props, props, |props| &mut props.sx)
   }
 
   fn view(&self) -> Html {
@@ -85,12 +88,10 @@ impl Component for MenuItem {
 
 impl MenuItem {
   fn format_classes(&self) -> Classes {
-    let mut classes = Classes::from(self.style.clone().to_string());
-
-    classes.push(self.props.class.clone());
+    let mut classes = self.style.classes(&self.props.class);
 
     if self.props.disabled {
-      classes.push("disabled");
+      classes.push(self.style.modifier("disabled"));
     }
 
     classes
@@ -1,3 +1,5 @@
 mod menu_item;
 
-pub use menu_item::MenuItem;
\ No newline at end of file
+pub use menu_item::MenuItem;
+
+pub(crate) use menu_item::component_css;
\ No newline at end of file
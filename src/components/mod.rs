@@ -1,4 +1,41 @@
 pub mod button;
 pub mod menu_item;
 pub mod button_icon;
-pub mod table;
\ No newline at end of file
+pub mod button_group;
+pub mod icon_button;
+pub mod table;
+pub mod theme_provider;
+pub mod typography;
+
+mod spinner;
+
+mod style_cache;
+pub(crate) mod css_scanner;
+
+use crate::theme::Theme;
+use style_cache::{with_theme_overrides, StyleScope};
+
+/// The stable root class of every component with its style sheet for
+/// `theme`, theme level overrides included. Used by `ssr::extract_css`.
+pub(crate) fn component_styles(theme: &Theme) -> Vec<(String, String)> {
+  let overrides = &theme.components;
+  let styles = vec![
+    ("theme_provider", theme_provider::component_css(theme)),
+    ("button", with_theme_overrides(&button::component_css(), &overrides.button.style_overrides)),
+    ("button_icon", with_theme_overrides(&button_icon::component_css(), &overrides.button_icon.style_overrides)),
+    ("button_group", with_theme_overrides(&button_group::component_css(), &overrides.button_group.style_overrides)),
+    ("icon_button", with_theme_overrides(&icon_button::component_css(), &overrides.icon_button.style_overrides)),
+    ("menu_item", with_theme_overrides(&menu_item::component_css(theme), &overrides.menu_item.style_overrides)),
+    ("table", with_theme_overrides(&table::table_css(), &overrides.table.style_overrides)),
+    ("table_head", with_theme_overrides(&table::table_head_css(), &overrides.table_head.style_overrides)),
+    ("table_body", with_theme_overrides(&table::table_body_css(), &overrides.table_body.style_overrides)),
+    ("table_row", with_theme_overrides(&table::table_row_css(), &overrides.table_row.style_overrides)),
+    ("table_cell", with_theme_overrides(&table::table_cell_css(), &overrides.table_cell.style_overrides)),
+    ("typography", with_theme_overrides(&typography::component_css(), &overrides.typography.style_overrides)),
+    ("spinner", spinner::component_css()),
+  ];
+
+  styles.into_iter()
+    .map(|(component, css)| (StyleScope::block(component), css))
+    .collect()
+}
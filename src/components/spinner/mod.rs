@@ -0,0 +1,4 @@
+mod spinner;
+
+pub(crate) use spinner::Spinner;
+pub(crate) use spinner::component_css;
\ No newline at end of file
@@ -0,0 +1,86 @@
+use std::rc::Rc;
+use yew::{
+  html, Component, ShouldRender, Html, ComponentLink, Properties, Classes,
+};
+use crate::theme::Theme;
+use crate::components::theme_provider::{subscribe_theme, ThemeSubscription};
+use crate::components::style_cache::{get_style, StyleScope};
+
+pub enum Msg {
+  SetTheme(Rc<Theme>),
+}
+
+/// The loading indicator of `Button` and `IconButton`. It only draws and
+/// animates itself, the host places and sizes it through `class`.
+pub struct Spinner {
+  style: StyleScope,
+  props: Props,
+  _theme_subscription: Option<ThemeSubscription>,
+}
+
+#[derive(Properties, Clone, PartialEq, Debug)]
+pub struct Props {
+    #[prop_or_default]
+    pub class: String,
+    /// Value of the `data-ui-element` attribute, e.g. `button-spinner`.
+    pub ui_element: &'static str,
+}
+
+/// Keyframes are global, their names are prefixed to stay clear of the page's own.
+pub(crate) fn component_css() -> String {
+  String::from(include_str!("spinner.scss"))
+}
+
+fn create_style(theme: &Theme) -> StyleScope {
+  get_style("spinner", theme, "", component_css)
+}
+
+impl Component for Spinner {
+  type Message = Msg;
+  type Properties = Props;
+
+  fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+    let (theme, theme_subscription) = subscribe_theme(&link, link.callback(Msg::SetTheme));
+
+    Self {
+      style: create_style(&theme),
+      props,
+      _theme_subscription: theme_subscription,
+    }
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::SetTheme(theme) => {
+        self.style = create_style(&theme);
+        true
+      }
+    }
+  }
+
+  fn change(&mut self, props: Self::Properties) -> ShouldRender {
+    if self.props != props {
+      self.props = props;
+
+      true
+    } else {
+      false
+    }
+  }
+
+  fn view(&self) -> Html {
+    let mut classes = Classes::from(self.style.root());
+
+    classes.push(self.props.class.clone());
+
+    html! {
+      <svg
+        class=classes
+        viewBox="0 0 50 50"
+        data-ui-element=self.props.ui_element
+      >
+        <circle class=self.style.element("path") cx="25" cy="25" r="20" fill="none" stroke-width="5"></circle>
+      </svg>
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,399 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use css_in_rust::Style;
+use yew::{Callback, Classes, Component, ComponentLink, ShouldRender};
+use crate::theme::{Theme, StyleOverride};
+use crate::components::theme_provider::{subscribe_theme, ensure_root_theme, ThemeSubscription};
+use crate::components::css_scanner::{split_items, replace_parent, CssItem};
+
+/// Where the class names of a component come from.
+#[derive(Clone, Debug)]
+pub enum StyleScope {
+  /// A generated style sheet, and the sheet of the theme's overrides when
+  /// the theme has any. Modifiers and elements are plain classes scoped by
+  /// the sheet's class.
+  Styled(Style, Option<Style>),
+  /// A style sheet served with the page, see `ssr::extract_css`. Only the
+  /// root class differs from `Styled`, it is the stable `dao-button`.
+  Static(&'static str),
+  /// No style sheet at all, only BEM class names for external CSS:
+  /// `dao-button`, `dao-button--loading`, `dao-button__content`.
+  Unstyled(&'static str),
+}
+
+impl StyleScope {
+  pub fn block(component: &str) -> String {
+    format!("dao-{}", component.replace('_', "-"))
+  }
+
+  /// Class of the component's root element.
+  pub fn root(&self) -> String {
+    match self {
+      StyleScope::Styled(style, None) => { style.to_string() }
+      StyleScope::Styled(style, Some(overrides)) => { format!("{} {}", style.to_string(), overrides.to_string()) }
+      StyleScope::Static(component) | StyleScope::Unstyled(component) => { StyleScope::block(component) }
+    }
+  }
+
+  /// Class of a state or variant of the root element, e.g. `loading`.
+  pub fn modifier(&self, modifier: &str) -> String {
+    match self {
+      StyleScope::Styled(..) | StyleScope::Static(_) => { String::from(modifier) }
+      StyleScope::Unstyled(component) => { format!("{}--{}", StyleScope::block(component), modifier) }
+    }
+  }
+
+  /// Class of an element inside the component, e.g. `content`.
+  pub fn element(&self, element: &str) -> String {
+    match self {
+      StyleScope::Styled(..) | StyleScope::Static(_) => { String::from(element) }
+      StyleScope::Unstyled(component) => { format!("{}__{}", StyleScope::block(component), element) }
+    }
+  }
+}
+
+// Overrides repeat the scope class to outrank every rule of the component
+// sheets. The most specific one, `&.contained:hover:not(.disabled):not(.loading)`,
+// counts five classes, so theme overrides use six. Instance overrides get one
+// more to also outrank the theme's overrides.
+const THEME_OVERRIDE_SCOPE: &str = "&&&&&&";
+const INSTANCE_OVERRIDE_SCOPE: &str = "&&&&&&&";
+
+/// Sheets keyed by component and the override CSS they were built with.
+type StyleMap = HashMap<(&'static str, String), Option<Style>>;
+/// Sheet of each component.
+type ComponentStyleMap = HashMap<&'static str, Option<Style>>;
+/// Sheets of each component, one per theme.
+type ThemeStyleMap = HashMap<&'static str, Vec<(Theme, Option<Style>)>>;
+
+thread_local! {
+  static STYLES: RefCell<ComponentStyleMap> = RefCell::new(HashMap::new());
+  static THEME_STYLES: RefCell<ThemeStyleMap> = RefCell::new(HashMap::new());
+  static THEME_OVERRIDE_STYLES: RefCell<StyleMap> = RefCell::new(HashMap::new());
+  static OVERRIDE_STYLES: RefCell<StyleMap> = RefCell::new(HashMap::new());
+}
+
+/// Component sheets are checked by `tests/style_sheets.rs`, so this only
+/// fails on broken overrides. The failure is logged once and cached, the
+/// component then renders without that sheet. Overrides have sheets of their
+/// own, a broken one leaves the component sheet and the other overrides alone.
+fn create_style(component: &str, css: String) -> Option<Style> {
+  match Style::create(String::from(component), css) {
+    Ok(style) => { Some(style) }
+    Err(error) => {
+      log::error!("invalid style of {}: {}", component, error);
+      None
+    }
+  }
+}
+
+/// Returns the sheet of the theme level `overrides` of a component, shared
+/// by every theme with the same overrides.
+fn get_theme_override_style(component: &'static str, overrides: &str) -> Option<Style> {
+  if overrides.trim().is_empty() {
+    return None;
+  }
+
+  THEME_OVERRIDE_STYLES.with(|styles| {
+    styles.borrow_mut()
+      .entry((component, String::from(overrides)))
+      .or_insert_with(|| create_style(component, scope_overrides(overrides, THEME_OVERRIDE_SCOPE)))
+      .clone()
+  })
+}
+
+fn to_scope(component: &'static str, style: Option<Style>, overrides: &str) -> StyleScope {
+  match style {
+    Some(style) => { StyleScope::Styled(style, get_theme_override_style(component, overrides)) }
+    None => { StyleScope::Unstyled(component) }
+  }
+}
+
+/// Replaces `&` with `scope` in selectors only, leaving declarations alone,
+/// e.g. `content: "&"` or `url(a.svg?x=1&y=2)`.
+fn scope_selectors(css: &str, scope: &str) -> String {
+  split_items(css).into_iter()
+    .map(|item| match item {
+      CssItem::Declaration(declaration) => { String::from(declaration) }
+      CssItem::Rule(prelude, body) => {
+        let prelude = replace_parent(prelude, scope).unwrap_or_else(|| String::from(prelude));
+
+        format!("{}{{{}}}", prelude, scope_selectors(body, scope))
+      }
+    })
+    .collect()
+}
+
+/// Scopes override CSS, either plain declarations or rules using `&`.
+fn scope_overrides(css: &str, scope: &str) -> String {
+  if css.trim().is_empty() {
+    String::new()
+  } else if css.contains('{') {
+    scope_selectors(css, scope)
+  } else {
+    format!("{} {{\n{}\n}}", scope, css)
+  }
+}
+
+/// Returns `css` followed by the theme level `style_overrides` of a component,
+/// as a single sheet for `ssr::extract_css`.
+pub fn with_theme_overrides(css: &str, overrides: &str) -> String {
+  format!("{}\n{}", css, scope_overrides(overrides, THEME_OVERRIDE_SCOPE))
+}
+
+/// Returns the style sheet of `component` with the theme level `overrides`
+/// applied. `css` is only called, and the sheet only registered, the first
+/// time; every instance shares the result. Unstyled themes get no sheet,
+/// neither do sheets that fail to parse. Overrides that fail to parse are
+/// left out on their own.
+pub fn get_style<F>(component: &'static str, theme: &Theme, overrides: &str, css: F) -> StyleScope
+where
+  F: FnOnce() -> String,
+{
+  if theme.unstyled {
+    return StyleScope::Unstyled(component);
+  }
+  if theme.static_styles {
+    return StyleScope::Static(component);
+  }
+
+  let style = STYLES.with(|styles| {
+    styles.borrow_mut()
+      .entry(component)
+      .or_insert_with(|| create_style(component, css()))
+      .clone()
+  });
+
+  to_scope(component, style, overrides)
+}
+
+/// Same as `get_style` for sheets built from the theme: each theme gets its
+/// own sheet, created once and shared by every instance rendered with it.
+/// Like css-in-rust, which never unmounts a sheet, the cache keeps them all,
+/// so switching back to a theme reuses its sheet.
+pub fn get_theme_style<F>(component: &'static str, theme: &Theme, overrides: &str, css: F) -> StyleScope
+where
+  F: FnOnce(&Theme) -> String,
+{
+  if theme.unstyled {
+    return StyleScope::Unstyled(component);
+  }
+  if theme.static_styles {
+    return StyleScope::Static(component);
+  }
+
+  let style = THEME_STYLES.with(|styles| {
+    let mut styles = styles.borrow_mut();
+    let entries = styles.entry(component).or_insert_with(Vec::new);
+
+    if let Some((_, style)) = entries.iter().find(|(t, _)| t == theme) {
+      return style.clone();
+    }
+
+    let style = create_style(component, css(theme));
+
+    entries.push((theme.clone(), style.clone()));
+    style
+  });
+
+  to_scope(component, style, overrides)
+}
+
+/// Returns the sheet of an instance `sx` prop from its CSS, shared by every
+/// instance with the same CSS. Sheets are never dropped: css-in-rust keeps
+/// every sheet it creates mounted, so dropping one from the cache would only
+/// mount a duplicate when its CSS comes back.
+fn get_override_style(component: &'static str, css: &str) -> Option<Style> {
+  OVERRIDE_STYLES.with(|styles| {
+    styles.borrow_mut()
+      .entry((component, String::from(css)))
+      .or_insert_with(|| create_style(component, scope_overrides(css, INSTANCE_OVERRIDE_SCOPE)))
+      .clone()
+  })
+}
+
+/// The sheet of an instance `sx` prop, along with the CSS it was built from.
+#[derive(Default)]
+struct OverrideStyle {
+  css: Option<String>,
+  style: Option<Style>,
+}
+
+impl OverrideStyle {
+  fn new(component: &'static str, sx: &Option<StyleOverride>, theme: &Theme) -> Self {
+    let mut style = OverrideStyle::default();
+
+    style.update(component, sx, theme);
+    style
+  }
+
+  /// Switches to the sheet of `sx` and returns whether its CSS changed.
+  /// Overrides are compared by the CSS they produce, so a themed closure
+  /// created on every render only counts as a change when its CSS does.
+  /// Unstyled themes ignore the prop.
+  fn update(&mut self, component: &'static str, sx: &Option<StyleOverride>, theme: &Theme) -> bool {
+    let css = if theme.unstyled {
+      None
+    } else {
+      sx.as_ref().map(|sx| sx.to_css(theme))
+    };
+
+    if css == self.css {
+      return false;
+    }
+
+    self.style = css.as_ref().and_then(|css| get_override_style(component, css));
+    self.css = css;
+    true
+  }
+
+  /// Class of the sheet, `None` without an override or when it failed to parse.
+  fn class(&self) -> Option<String> {
+    self.style.as_ref().map(|style| style.to_string())
+  }
+}
+
+/// The theme of a component and its sheets, kept in step with the closest
+/// `ThemeProvider`: the sheet `create_style` builds from the theme and the
+/// sheet of the `sx` prop.
+pub struct ComponentStyle {
+  component: &'static str,
+  create_style: fn(&Theme) -> StyleScope,
+  scope: StyleScope,
+  sx_style: OverrideStyle,
+  theme: Rc<Theme>,
+  _theme_subscription: Option<ThemeSubscription>,
+}
+
+impl ComponentStyle {
+  /// Subscribes to the theme and writes the default `:root` variables when
+  /// they are needed. `on_theme` is called with every new theme, which the
+  /// component hands back to `set_theme`.
+  pub fn new<COMP: Component>(
+    link: &ComponentLink<COMP>,
+    on_theme: Callback<Rc<Theme>>,
+    component: &'static str,
+    create_style: fn(&Theme) -> StyleScope,
+    sx: &Option<StyleOverride>,
+  ) -> Self {
+    let (theme, theme_subscription) = subscribe_theme(link, on_theme);
+
+    // Unstyled components have no use for the variables, and a served
+    // sheet already has the theme's own `:root` rule.
+    if !theme.unstyled && !theme.static_styles {
+      ensure_root_theme();
+    }
+
+    ComponentStyle {
+      component,
+      create_style,
+      scope: create_style(&theme),
+      sx_style: OverrideStyle::new(component, sx, &theme),
+      theme,
+      _theme_subscription: theme_subscription,
+    }
+  }
+
+  pub fn theme(&self) -> &Theme {
+    &self.theme
+  }
+
+  pub fn set_theme(&mut self, theme: Rc<Theme>, sx: &Option<StyleOverride>) {
+    self.scope = (self.create_style)(&theme);
+    self.sx_style.update(self.component, sx, &theme);
+    self.theme = theme;
+  }
+
+  /// Replaces `props` with `new` and returns whether they changed. The `sx`
+  /// prop, reached through `sx`, counts as changed only when its CSS does.
+  pub fn change<P: PartialEq>(
+    &mut self,
+    props: &mut P,
+    mut new: P,
+    sx: fn(&mut P) -> &mut Option<StyleOverride>,
+  ) -> ShouldRender {
+    let sx_changed = self.sx_style.update(self.component, sx(&mut new), &self.theme);
+
+    *sx(props) = sx(&mut new).clone();
+
+    if *props != new {
+      *props = new;
+
+      true
+    } else {
+      sx_changed
+    }
+  }
+
+  /// Classes of the root element: the sheet's, then `class`, then the `sx` prop's.
+  pub fn classes(&self, class: &str) -> Classes {
+    let mut classes = Classes::from(self.scope.root());
+
+    classes.push(class.to_string());
+
+    if let Some(sx_class) = self.sx_style.class() {
+      classes.push(sx_class);
+    }
+
+    classes
+  }
+
+  pub fn modifier(&self, modifier: &str) -> String {
+    self.scope.modifier(modifier)
+  }
+
+  pub fn element(&self, element: &str) -> String {
+    self.scope.element(element)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{scope_overrides, StyleScope};
+
+  #[test]
+  fn unstyled_scopes_use_bem_names() {
+    let scope = StyleScope::Unstyled("button");
+
+    assert_eq!(StyleScope::block("button_group"), "dao-button-group");
+    assert_eq!(scope.root(), "dao-button");
+    assert_eq!(scope.modifier("loading"), "dao-button--loading");
+    assert_eq!(scope.element("content"), "dao-button__content");
+  }
+
+  #[test]
+  fn static_scopes_use_the_block_as_root() {
+    let scope = StyleScope::Static("button");
+
+    assert_eq!(scope.root(), "dao-button");
+    assert_eq!(scope.modifier("loading"), "loading");
+    assert_eq!(scope.element("content"), "content");
+  }
+
+  #[test]
+  fn wraps_declarations() {
+    assert_eq!(scope_overrides("margin: 0;", "&&"), "&& {\nmargin: 0;\n}");
+    assert_eq!(scope_overrides("  ", "&&"), "");
+  }
+
+  #[test]
+  fn scopes_selectors_only() {
+    assert_eq!(
+      scope_overrides("&:hover, a > & { color: red; }", "&&"),
+      "&&:hover, a > && { color: red; }",
+    );
+    assert_eq!(
+      scope_overrides("& { content: \"&\"; background: url(a.svg?x=1&y=2); }", "&&"),
+      "&& { content: \"&\"; background: url(a.svg?x=1&y=2); }",
+    );
+    assert_eq!(
+      scope_overrides("& { background: url(data:image/png;base64,AA&); } &[title=\"a&b\"] { margin: 0; }", "&&"),
+      "&& { background: url(data:image/png;base64,AA&); } &&[title=\"a&b\"] { margin: 0; }",
+    );
+    assert_eq!(
+      scope_overrides("@media (min-width: 768px) { &:not(&.x) { margin: 0; } }", "&&"),
+      "@media (min-width: 768px) { &&:not(&&.x) { margin: 0; } }",
+    );
+  }
+}
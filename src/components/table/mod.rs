@@ -8,4 +8,10 @@ pub use table_cell::table_cell::TableCell;
 pub use table_row::table_row::TableRow;
 pub use table_head::table_head::TableHead;
 pub use table_body::table_body::TableBody;
-pub use table::{Table, TableSize};
\ No newline at end of file
+pub use table::{Table, TableSize};
+
+pub(crate) use table::component_css as table_css;
+pub(crate) use table_head::table_head::component_css as table_head_css;
+pub(crate) use table_body::table_body::component_css as table_body_css;
+pub(crate) use table_row::table_row::component_css as table_row_css;
+pub(crate) use table_cell::table_cell::component_css as table_cell_css;
\ No newline at end of file
@@ -1,4 +1,4 @@
-use css_in_rust::Style;
+use std::rc::Rc;
 use yew::{
   html, Component, ShouldRender, Html, ComponentLink, Properties, Classes, NodeRef,
   html::{
@@ -6,7 +6,8 @@ use yew::{
   },
   virtual_dom::{VComp, VChild},
 };
-use crate::theme::{Theme};
+use crate::theme::{Theme, Density, StyleOverride};
+use crate::components::style_cache::{get_style, StyleScope, ComponentStyle};
 use crate::components::table::table_body::table_body::{TableBody, Props as TableBodyProps};
 use crate::components::table::table_head::table_head::{TableHead, Props as TableHeadProps};
 
@@ -40,8 +41,12 @@ pub enum TableSize {
   Medium,
 }
 
+pub enum Msg {
+  SetTheme(Rc<Theme>),
+}
+
 pub struct Table {
-  style: Style,
+  style: ComponentStyle,
   props: Props,
 }
 
@@ -51,8 +56,19 @@ pub struct Props {
     pub class: String,
     #[prop_or_default]
     pub children: ChildrenRenderer<ChildVariant>,
-    #[prop_or(TableSize::Medium)]
-    pub size: TableSize,
+    /// Follows the theme density when not set.
+    #[prop_or_default]
+    pub size: Option<TableSize>,
+    #[prop_or_default]
+    pub sx: Option<StyleOverride>,
+}
+
+/// Tables have no large size, spacious themes keep them at medium.
+fn get_density_size(density: Density) -> TableSize {
+  match density {
+    Density::Compact => { TableSize::Small }
+    Density::Comfortable | Density::Spacious => { TableSize::Medium }
+  }
 }
 
 impl<CHILD> From<VChild<CHILD>> for ChildVariant
@@ -76,53 +92,54 @@ impl From<ChildVariant> for Html {
   }
 }
 
+pub(crate) fn component_css() -> String {
+  String::from(include_str!("table.scss"))
+}
+
+fn create_style(theme: &Theme) -> StyleScope {
+  get_style("table", theme, &theme.components.table.style_overrides, component_css)
+}
+
 impl Component for Table {
-  type Message = ();
+  type Message = Msg;
   type Properties = Props;
 
-  fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
-    let theme = Theme::default();
-    let style = Style::create(
-      String::from("table"),
-      include_str!("table.scss")
-        .replace("$font_family", &theme.typography.font_family),
-    )
-    .expect("An error occured while creating the style");
-
+  fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
     Self {
-      style,
+      style: ComponentStyle::new(&link, link.callback(Msg::SetTheme), "table", create_style, &props.sx),
       props,
     }
   }
 
-  fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-    false
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::SetTheme(theme) => {
+        self.style.set_theme(theme, &self.props.sx);
+        true
+      }
+    }
   }
 
   fn change(&mut self, props: Self::Properties) -> ShouldRender {
-    if self.props != props {
-      self.props = props;
-      
-      true
-    } else {
-      false
-    }
+    self.style.change(&mut self.props, props, |props| &mut props.sx)
   }
 
   fn view(&self) -> Html {
+    let size = self.props.size.clone().unwrap_or_else(|| get_density_size(self.style.theme().density));
+
     html! {
       <table
-        class=Classes::from(self.style.clone().to_string())
+        class=self.format_classes()
       >
         {
           self.props.children.iter()
             .filter(|c| matches!(c.props, Variants::Head(_)) || matches!(c.props, Variants::Body(_)))
             .map(|mut c| {
               if let Variants::Head(ref mut head_props) = c.props {
-                head_props.size = self.props.size.clone();
+                head_props.size = size.clone();
               }
               if let Variants::Body(ref mut body_props) = c.props {
-                body_props.size = self.props.size.clone();
+                body_props.size = size.clone();
               }
              
               c
@@ -132,4 +149,10 @@ impl Component for Table {
       </table>
     }
   }
-}
\ No newline at end of file
+}
+
+impl Table {
+  fn format_classes(&self) -> Classes {
+    self.style.classes(&self.props.class)
+  }
+}
@@ -1,10 +1,11 @@
-use css_in_rust::Style;
+use std::rc::Rc;
 use yew::{
   html, Component, ShouldRender, Html, ComponentLink,
   Properties, Children, Classes,
 };
 use crate::components::table::TableSize;
-use crate::theme::{Theme};
+use crate::theme::{Theme, StyleOverride};
+use crate::components::style_cache::{get_style, StyleScope, ComponentStyle};
 
 #[derive(Clone, PartialEq, Debug)]
 pub enum TableCellVariant {
@@ -20,8 +21,12 @@ pub enum TableCellAlign {
   Center,
 }
 
+pub enum Msg {
+  SetTheme(Rc<Theme>),
+}
+
 pub struct TableCell {
-  style: Style,
+  style: ComponentStyle,
   props: Props,
 }
 
@@ -37,7 +42,8 @@ pub struct Props {
     pub variant: Option<TableCellVariant>,
     #[prop_or(TableSize::Medium)]
     pub size: TableSize,
-
+    #[prop_or_default]
+    pub sx: Option<StyleOverride>,
 }
 
 fn get_align_class(size: &TableCellAlign) -> String {
@@ -59,37 +65,36 @@ fn get_size_class(size: &TableSize) -> String {
   format!("size-{}", s)
 }
 
+pub(crate) fn component_css() -> String {
+  String::from(include_str!("table_cell.scss"))
+}
+
+fn create_style(theme: &Theme) -> StyleScope {
+  get_style("table_cell", theme, &theme.components.table_cell.style_overrides, component_css)
+}
+
 impl Component for TableCell {
-  type Message = ();
+  type Message = Msg;
   type Properties = Props;
 
-  fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
-    let theme = Theme::default();
-    let style = Style::create(
-      String::from("table_cell"),
-      include_str!("table_cell.scss")
-        .replace("$palette_divider", &theme.palette.divider),
-    )
-    .expect("An error occured while creating the style");
-
+  fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
     Self {
-      style,
+      style: ComponentStyle::new(&link, link.callback(Msg::SetTheme), "table_cell", create_style, &props.sx),
       props,
     }
   }
 
-  fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-    false
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::SetTheme(theme) => {
+        self.style.set_theme(theme, &self.props.sx);
+        true
+      }
+    }
   }
 
   fn change(&mut self, props: Self::Properties) -> ShouldRender {
-    if self.props != props {
-      self.props = props;
-      
-      true
-    } else {
-      false
-    }
+    self.style.change(&mut self.props, props, |props| &mut props.sx)
   }
 
   fn view(&self) -> Html {
@@ -115,11 +120,10 @@ impl Component for TableCell {
 
 impl TableCell {
   fn format_classes(&self) -> Classes {
-    let mut classes = Classes::from(self.style.clone().to_string());
+    let mut classes = self.style.classes(&self.props.class);
 
-    classes.push(self.props.class.clone());
-    classes.push(get_size_class(&self.props.size));
-    classes.push(get_align_class(&self.props.align));
+    classes.push(self.style.modifier(&get_size_class(&self.props.size)));
+    classes.push(self.style.modifier(&get_align_class(&self.props.align)));
 
     classes
   }
@@ -1,4 +1,4 @@
-use css_in_rust::Style;
+use std::rc::Rc;
 use yew::{
   html, Component, ShouldRender, Html, ComponentLink,
   Properties, Classes, NodeRef,
@@ -12,6 +12,8 @@ use crate::components::table::table_row::table_row::{
   Props as TableRowProps,
 };
 use crate::components::table::TableSize;
+use crate::theme::{Theme, StyleOverride};
+use crate::components::style_cache::{get_style, StyleScope, ComponentStyle};
 use crate::components::table::table_cell::table_cell::TableCellVariant;
 
 #[derive(Clone, PartialEq)]
@@ -30,8 +32,12 @@ pub struct ChildVariant {
   props: Variants,
 }
 
+pub enum Msg {
+  SetTheme(Rc<Theme>),
+}
+
 pub struct TableHead {
-  style: Style,
+  style: ComponentStyle,
   props: Props,
 }
 
@@ -43,6 +49,8 @@ pub struct Props {
     pub children: ChildrenRenderer<ChildVariant>,
     #[prop_or(TableSize::Small)]
     pub size: TableSize,
+    #[prop_or_default]
+    pub sx: Option<StyleOverride>,
 }
 
 impl<CHILD> From<VChild<CHILD>> for ChildVariant
@@ -65,41 +73,42 @@ impl From<ChildVariant> for Html {
   }
 }
 
+pub(crate) fn component_css() -> String {
+  String::from(include_str!("table_head.scss"))
+}
+
+fn create_style(theme: &Theme) -> StyleScope {
+  get_style("table_head", theme, &theme.components.table_head.style_overrides, component_css)
+}
+
 impl Component for TableHead {
-  type Message = ();
+  type Message = Msg;
   type Properties = Props;
 
-  fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
-    let style = Style::create(
-      String::from("table_head"),
-      include_str!("table_head.scss"),
-    )
-    .expect("An error occured while creating the style");
-
+  fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
     Self {
-      style,
+      style: ComponentStyle::new(&link, link.callback(Msg::SetTheme), "table_head", create_style, &props.sx),
       props,
     }
   }
 
-  fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-    false
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::SetTheme(theme) => {
+        self.style.set_theme(theme, &self.props.sx);
+        true
+      }
+    }
   }
 
   fn change(&mut self, props: Self::Properties) -> ShouldRender {
-    if self.props != props {
-      self.props = props;
-      
-      true
-    } else {
-      false
-    }
+    self.style.change(&mut self.props, props, |props| &mut props.sx)
   }
 
   fn view(&self) -> Html {
     html! {
       <thead
-        class=Classes::from(self.style.clone().to_string())
+        class=self.format_classes()
       >
         {
           self.props.children.iter()
@@ -115,4 +124,10 @@ impl Component for TableHead {
       </thead>
     }
   }
-}
\ No newline at end of file
+}
+
+impl TableHead {
+  fn format_classes(&self) -> Classes {
+    self.style.classes(&self.props.class)
+  }
+}
@@ -0,0 +1,117 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use gloo_events::EventListener;
+use web_sys::MediaQueryList;
+use yew::{Component, ComponentLink, Callback};
+use crate::theme::{Theme, Breakpoint, Breakpoints};
+use crate::components::theme_provider::{subscribe_theme, ThemeSubscription};
+
+/// Calls a callback every time the viewport moves to another breakpoint.
+/// Listening stops when the value is dropped.
+pub struct BreakpointListener {
+  current: Rc<Cell<Breakpoint>>,
+  _listeners: Vec<EventListener>,
+}
+
+fn match_breakpoint(queries: &[(Breakpoint, MediaQueryList)]) -> Breakpoint {
+  queries.iter()
+    .rev()
+    .find(|(_, query)| query.matches())
+    .map(|&(b, _)| b)
+    .unwrap_or(Breakpoint::Xs)
+}
+
+impl BreakpointListener {
+  /// Returns `None` when there is no window to listen to, e.g. when
+  /// rendering on the server.
+  pub fn new(breakpoints: &Breakpoints, callback: Callback<Breakpoint>) -> Option<Self> {
+    let window = web_sys::window()?;
+    let mut queries = vec![];
+
+    for (breakpoint, _) in breakpoints.values() {
+      let query = window.match_media(&breakpoints.up_query(breakpoint)).ok()??;
+
+      queries.push((breakpoint, query));
+    }
+
+    let queries = Rc::new(queries);
+    let current = Rc::new(Cell::new(match_breakpoint(&queries)));
+    let listeners = queries.iter()
+      .map(|(_, query)| {
+        let queries = queries.clone();
+        let current = current.clone();
+        let callback = callback.clone();
+
+        EventListener::new(query, "change", move |_| {
+          let breakpoint = match_breakpoint(&queries);
+
+          if breakpoint != current.get() {
+            current.set(breakpoint);
+            callback.emit(breakpoint);
+          }
+        })
+      })
+      .collect();
+
+    Some(BreakpointListener {
+      current,
+      _listeners: listeners,
+    })
+  }
+
+  /// The breakpoint of the viewport right now.
+  pub fn current(&self) -> Breakpoint {
+    self.current.get()
+  }
+}
+
+/// Keeps a component subscribed to breakpoint changes, see `subscribe_breakpoint`.
+/// Listening stops when the value is dropped.
+pub struct BreakpointSubscription {
+  _listener: Rc<RefCell<Option<BreakpointListener>>>,
+  _theme_subscription: Option<ThemeSubscription>,
+}
+
+/// Returns the current breakpoint, using the breakpoints of the closest
+/// `ThemeProvider`, and calls `callback` every time it changes. The queries
+/// follow the provider when it switches to a theme with other breakpoints.
+/// Without a window the breakpoint is `Xs` and no subscription is returned.
+pub fn subscribe_breakpoint<COMP: Component>(
+  link: &ComponentLink<COMP>,
+  callback: Callback<Breakpoint>,
+) -> (Breakpoint, Option<BreakpointSubscription>) {
+  let listener: Rc<RefCell<Option<BreakpointListener>>> = Rc::new(RefCell::new(None));
+  let on_theme = {
+    let listener = listener.clone();
+    let callback = callback.clone();
+
+    Callback::from(move |theme: Rc<Theme>| {
+      let previous = listener.borrow().as_ref().map(BreakpointListener::current);
+      let next = BreakpointListener::new(&theme.breakpoints, callback.clone());
+      let current = next.as_ref().map(BreakpointListener::current);
+
+      *listener.borrow_mut() = next;
+
+      if let Some(breakpoint) = current {
+        if Some(breakpoint) != previous {
+          callback.emit(breakpoint);
+        }
+      }
+    })
+  };
+  let (theme, theme_subscription) = subscribe_theme(link, on_theme);
+
+  match BreakpointListener::new(&theme.breakpoints, callback) {
+    Some(current_listener) => {
+      let current = current_listener.current();
+
+      *listener.borrow_mut() = Some(current_listener);
+
+      (current, Some(BreakpointSubscription {
+        _listener: listener,
+        _theme_subscription: theme_subscription,
+      }))
+    }
+    None => { (Breakpoint::Xs, None) }
+  }
+}
\ No newline at end of file
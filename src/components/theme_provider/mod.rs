@@ -0,0 +1,15 @@
+mod theme_provider;
+mod breakpoint_listener;
+
+pub use theme_provider::ThemeProvider;
+pub use theme_provider::ColorScheme;
+pub use theme_provider::ThemeSubscription;
+pub use theme_provider::get_theme;
+pub use theme_provider::subscribe_theme;
+pub use theme_provider::set_root_theme;
+pub use theme_provider::ensure_root_theme;
+pub use breakpoint_listener::BreakpointListener;
+pub use breakpoint_listener::BreakpointSubscription;
+pub use breakpoint_listener::subscribe_breakpoint;
+
+pub(crate) use theme_provider::component_css;
\ No newline at end of file
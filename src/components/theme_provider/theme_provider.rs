@@ -0,0 +1,357 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use gloo_events::EventListener;
+use web_sys::MediaQueryList;
+use yew::{
+  html, Component, ShouldRender, Html, ComponentLink,
+  Properties, Children, Callback, Classes,
+};
+use crate::theme::Theme;
+use crate::components::style_cache::{get_theme_style, StyleScope};
+
+const DARK_SCHEME_QUERY: &str = "(prefers-color-scheme: dark)";
+#[cfg(target_arch = "wasm32")]
+const ROOT_STYLE_ID: &str = "dao-theme-root";
+
+pub enum Msg {
+  SystemSchemeChanged(bool),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[allow(dead_code)]
+pub enum ColorScheme {
+  Light,
+  Dark,
+  System,
+}
+
+#[derive(Default)]
+struct Subscribers {
+  next_id: usize,
+  callbacks: HashMap<usize, Callback<Rc<Theme>>>,
+}
+
+/// Keeps a component subscribed to theme changes of its `ThemeProvider`.
+/// The subscription is removed when the value is dropped.
+pub struct ThemeSubscription {
+  id: usize,
+  subscribers: Rc<RefCell<Subscribers>>,
+}
+
+impl Drop for ThemeSubscription {
+  fn drop(&mut self) {
+    self.subscribers.borrow_mut().callbacks.remove(&self.id);
+  }
+}
+
+pub struct ThemeProvider {
+  style: StyleScope,
+  props: Props,
+  link: ComponentLink<Self>,
+  theme: Rc<Theme>,
+  prefers_dark: bool,
+  subscribers: Rc<RefCell<Subscribers>>,
+  _media_listener: Option<EventListener>,
+}
+
+#[derive(Properties, Clone, PartialEq, Debug)]
+pub struct Props {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_else(Theme::default)]
+    pub theme: Theme,
+    #[prop_or_default]
+    pub dark_theme: Option<Theme>,
+    #[prop_or(ColorScheme::Light)]
+    pub color_scheme: ColorScheme,
+}
+
+fn dark_scheme_query() -> Option<MediaQueryList> {
+  web_sys::window()?.match_media(DARK_SCHEME_QUERY).ok()?
+}
+
+/// Reads `prefers-color-scheme` and listens to its changes while the
+/// provider follows the system. Other schemes get no listener.
+fn watch_system_scheme(
+  link: &ComponentLink<ThemeProvider>,
+  color_scheme: &ColorScheme,
+) -> (bool, Option<EventListener>) {
+  if *color_scheme != ColorScheme::System {
+    return (false, None);
+  }
+
+  match dark_scheme_query() {
+    Some(query) => {
+      let callback = link.callback(Msg::SystemSchemeChanged);
+      let list = query.clone();
+      let listener = EventListener::new(&query, "change", move |_| {
+        callback.emit(list.matches());
+      });
+
+      (query.matches(), Some(listener))
+    }
+    None => { (false, None) }
+  }
+}
+
+pub(crate) fn component_css(theme: &Theme) -> String {
+  format!(
+    "& {{\n  display: contents;\n{}}}\n{}",
+    theme.to_css_declarations(),
+    theme.to_reduced_motion_css("&"),
+  )
+}
+
+fn create_style(theme: &Theme) -> StyleScope {
+  get_theme_style("theme_provider", theme, "", component_css)
+}
+
+/// Without a `dark_theme`, the dark scheme keeps everything of `theme` but
+/// its palette and shadows.
+fn resolve_theme(props: &Props, prefers_dark: bool) -> Theme {
+  let dark = match props.color_scheme {
+    ColorScheme::Light => { false }
+    ColorScheme::Dark => { true }
+    ColorScheme::System => { prefers_dark }
+  };
+
+  if dark {
+    props.dark_theme.clone().unwrap_or_else(|| Theme {
+      palette: Theme::get_dark_palette(),
+      shadows: Theme::get_dark_shadows(),
+      ..props.theme.clone()
+    })
+  } else {
+    props.theme.clone()
+  }
+}
+
+impl Component for ThemeProvider {
+  type Message = Msg;
+  type Properties = Props;
+
+  fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+    let (prefers_dark, media_listener) = watch_system_scheme(&link, &props.color_scheme);
+    let theme = resolve_theme(&props, prefers_dark);
+
+    Self {
+      style: create_style(&theme),
+      theme: Rc::new(theme),
+      props,
+      link,
+      prefers_dark,
+      subscribers: Rc::new(RefCell::new(Subscribers::default())),
+      _media_listener: media_listener,
+    }
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::SystemSchemeChanged(prefers_dark) => {
+        self.prefers_dark = prefers_dark;
+        self.apply_theme()
+      }
+    }
+  }
+
+  fn change(&mut self, props: Self::Properties) -> ShouldRender {
+    if self.props != props {
+      if self.props.color_scheme != props.color_scheme {
+        let (prefers_dark, media_listener) = watch_system_scheme(&self.link, &props.color_scheme);
+
+        self.prefers_dark = prefers_dark;
+        self._media_listener = media_listener;
+      }
+      self.props = props;
+      self.apply_theme();
+
+      true
+    } else {
+      false
+    }
+  }
+
+  fn view(&self) -> Html {
+    html! {
+      <div
+        class=Classes::from(self.style.root())
+        style=self.get_inline_style()
+      >
+        {self.props.children.clone()}
+      </div>
+    }
+  }
+}
+
+impl ThemeProvider {
+  /// Without a generated sheet to hold the variables, they are set inline:
+  /// for unstyled themes, and for sheets that failed to parse, which
+  /// `get_theme_style` turns into `StyleScope::Unstyled`. With static styles
+  /// the extracted `dao-theme-provider` rule holds those of a single theme,
+  /// setting them inline keeps nested providers with other themes right.
+  fn get_inline_style(&self) -> Option<String> {
+    if !matches!(self.style, StyleScope::Styled(..)) {
+      Some(format!("display: contents;\n{}", self.theme.to_css_declarations()))
+    } else {
+      None
+    }
+  }
+
+  fn apply_theme(&mut self) -> ShouldRender {
+    let theme = resolve_theme(&self.props, self.prefers_dark);
+
+    if theme != *self.theme {
+      self.style = create_style(&theme);
+      self.theme = Rc::new(theme);
+
+      let callbacks: Vec<Callback<Rc<Theme>>> = self.subscribers.borrow()
+        .callbacks
+        .values()
+        .cloned()
+        .collect();
+
+      for callback in callbacks {
+        callback.emit(self.theme.clone());
+      }
+
+      true
+    } else {
+      false
+    }
+  }
+}
+
+fn find_provider<COMP: Component>(
+  link: &ComponentLink<COMP>,
+) -> Option<(Rc<Theme>, Rc<RefCell<Subscribers>>)> {
+  let mut scope = link.get_parent();
+
+  while let Some(s) = scope {
+    if *s.get_type_id() == TypeId::of::<ThemeProvider>() {
+      let provider = s.clone().downcast::<ThemeProvider>();
+      let found = provider.get_component()
+        .map(|c| (c.theme.clone(), c.subscribers.clone()));
+
+      if found.is_some() {
+        return found;
+      }
+    }
+
+    scope = s.get_parent();
+  }
+
+  None
+}
+
+/// Returns the theme of the closest `ThemeProvider` above the component,
+/// or `Theme::default()` if there is none.
+pub fn get_theme<COMP: Component>(link: &ComponentLink<COMP>) -> Rc<Theme> {
+  find_provider(link)
+    .map(|(theme, _)| theme)
+    .unwrap_or_default()
+}
+
+/// Same as `get_theme`, but also registers `callback` to be called every time
+/// the provider switches to another theme.
+pub fn subscribe_theme<COMP: Component>(
+  link: &ComponentLink<COMP>,
+  callback: Callback<Rc<Theme>>,
+) -> (Rc<Theme>, Option<ThemeSubscription>) {
+  match find_provider(link) {
+    Some((theme, subscribers)) => {
+      let id = {
+        let mut s = subscribers.borrow_mut();
+        let id = s.next_id;
+
+        s.next_id += 1;
+        s.callbacks.insert(id, callback);
+        id
+      };
+
+      (theme, Some(ThemeSubscription { id, subscribers }))
+    }
+    None => { (Rc::new(Theme::default()), None) }
+  }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn mount_root_style(theme: Option<&Theme>) -> Option<()> {
+  let document = web_sys::window()?.document()?;
+  let element = match document.get_element_by_id(ROOT_STYLE_ID) {
+    Some(_) if theme.is_none() => { return Some(()) }
+    Some(element) => { element }
+    None => {
+      let element = document.create_element("style").ok()?;
+
+      element.set_id(ROOT_STYLE_ID);
+      document.head()?.append_child(&element).ok()?;
+      element
+    }
+  };
+  let css = match theme {
+    Some(theme) => { theme.to_root_css() }
+    None => { Theme::default().to_root_css() }
+  };
+
+  element.set_text_content(Some(&css));
+
+  Some(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn mount_root_style(_theme: Option<&Theme>) -> Option<()> {
+  Some(())
+}
+
+/// Writes `theme` as document wide `:root` variables, replacing the ones
+/// written before. Components outside of any `ThemeProvider` use them.
+pub fn set_root_theme(theme: &Theme) {
+  mount_root_style(Some(theme));
+}
+
+/// Writes the default theme as `:root` variables unless they are already in the document.
+pub fn ensure_root_theme() {
+  mount_root_style(None);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::theme::Density;
+
+  #[test]
+  fn dark_scheme_keeps_the_theme_settings() {
+    let theme = Theme {
+      density: Density::Compact,
+      unstyled: true,
+      ..Theme::default()
+    };
+    let props = Props::builder()
+      .theme(theme.clone())
+      .color_scheme(ColorScheme::System)
+      .build();
+    let dark = resolve_theme(&props, true);
+
+    assert_eq!(dark.palette, Theme::get_dark_palette());
+    assert_eq!(dark.shadows, Theme::get_dark_shadows());
+    assert_eq!(dark.density, Density::Compact);
+    assert!(dark.unstyled);
+    assert_eq!(resolve_theme(&props, false), theme);
+  }
+
+  #[test]
+  fn dark_scheme_uses_the_dark_theme() {
+    let dark_theme = Theme {
+      spacing_unit: 4.0,
+      ..Theme::dark()
+    };
+    let props = Props::builder()
+      .dark_theme(Some(dark_theme.clone()))
+      .color_scheme(ColorScheme::Dark)
+      .build();
+
+    assert_eq!(resolve_theme(&props, false), dark_theme);
+  }
+}
@@ -0,0 +1,8 @@
+mod typography;
+
+pub use typography::Typography;
+pub use typography::TypographyVariant;
+pub use typography::TypographyColor;
+pub use typography::TypographyAlign;
+
+pub(crate) use typography::component_css;
@@ -0,0 +1,234 @@
+use std::rc::Rc;
+use yew::{
+  html, Component, ShouldRender, Html, ComponentLink,
+  Properties, Children, Classes,
+};
+use crate::theme::{Theme, StyleOverride, is_valid_color_name};
+use crate::components::style_cache::{get_style, StyleScope, ComponentStyle};
+
+#[derive(Clone, PartialEq, Debug)]
+#[allow(dead_code)]
+pub enum TypographyVariant {
+  H1,
+  H2,
+  H3,
+  H4,
+  H5,
+  H6,
+  Subtitle,
+  Body1,
+  Body2,
+  Caption,
+  Overline,
+  Button,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[allow(dead_code)]
+pub enum TypographyColor {
+  Inherit,
+  TextPrimary,
+  TextSecondary,
+  Primary,
+  Secondary,
+  Error,
+  Success,
+  Warning,
+  Info,
+  /// A color registered with `Palette::add_color`.
+  Custom(String),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[allow(dead_code)]
+pub enum TypographyAlign {
+  Inherit,
+  Left,
+  Center,
+  Right,
+  Justify,
+}
+
+pub enum Msg {
+  SetTheme(Rc<Theme>),
+}
+
+pub struct Typography {
+  style: ComponentStyle,
+  /// Inline variables of a custom color, checked once per color.
+  color_style: Option<String>,
+  props: Props,
+}
+
+#[derive(Properties, Clone, PartialEq, Debug)]
+pub struct Props {
+    #[prop_or_default]
+    pub class: String,
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or(TypographyVariant::Body1)]
+    pub variant: TypographyVariant,
+    #[prop_or(TypographyColor::Inherit)]
+    pub color: TypographyColor,
+    #[prop_or(TypographyAlign::Inherit)]
+    pub align: TypographyAlign,
+    #[prop_or(false)]
+    pub no_wrap: bool,
+    #[prop_or(false)]
+    pub gutter_bottom: bool,
+    #[prop_or_default]
+    pub sx: Option<StyleOverride>,
+}
+
+fn get_variant_class(variant: &TypographyVariant) -> String {
+  let v = match variant {
+    TypographyVariant::H1 => { "h1" }
+    TypographyVariant::H2 => { "h2" }
+    TypographyVariant::H3 => { "h3" }
+    TypographyVariant::H4 => { "h4" }
+    TypographyVariant::H5 => { "h5" }
+    TypographyVariant::H6 => { "h6" }
+    TypographyVariant::Subtitle => { "subtitle" }
+    TypographyVariant::Body1 => { "body1" }
+    TypographyVariant::Body2 => { "body2" }
+    TypographyVariant::Caption => { "caption" }
+    TypographyVariant::Overline => { "overline" }
+    TypographyVariant::Button => { "button" }
+  };
+
+  format!("variant-{}", v)
+}
+
+fn get_color_class(color: &TypographyColor) -> Option<String> {
+  let c = match color {
+    TypographyColor::Inherit => { return None }
+    TypographyColor::Custom(_) => { return None }
+    TypographyColor::TextPrimary => { "text-primary" }
+    TypographyColor::TextSecondary => { "text-secondary" }
+    TypographyColor::Primary => { "primary" }
+    TypographyColor::Secondary => { "secondary" }
+    TypographyColor::Error => { "error" }
+    TypographyColor::Success => { "success" }
+    TypographyColor::Warning => { "warning" }
+    TypographyColor::Info => { "info" }
+  };
+
+  Some(format!("color-{}", c))
+}
+
+/// Invalid custom color names are left out rather than written into the
+/// inline style, see `is_valid_color_name`.
+fn get_color_style(color: &TypographyColor) -> Option<String> {
+  match color {
+    TypographyColor::Custom(name) if is_valid_color_name(name) => {
+      Some(format!("color: var(--dao-palette-{}-text);", name))
+    }
+    TypographyColor::Custom(name) => {
+      log::error!("invalid custom color name \"{}\"", name);
+      None
+    }
+    _ => { None }
+  }
+}
+
+fn get_align_class(align: &TypographyAlign) -> Option<String> {
+  let a = match align {
+    TypographyAlign::Inherit => { return None }
+    TypographyAlign::Left => { "left" }
+    TypographyAlign::Center => { "center" }
+    TypographyAlign::Right => { "right" }
+    TypographyAlign::Justify => { "justify" }
+  };
+
+  Some(format!("align-{}", a))
+}
+
+fn get_element(variant: &TypographyVariant) -> &'static str {
+  match variant {
+    TypographyVariant::H1 => { "h1" }
+    TypographyVariant::H2 => { "h2" }
+    TypographyVariant::H3 => { "h3" }
+    TypographyVariant::H4 => { "h4" }
+    TypographyVariant::H5 => { "h5" }
+    TypographyVariant::H6 | TypographyVariant::Subtitle => { "h6" }
+    TypographyVariant::Body1 | TypographyVariant::Body2 => { "p" }
+    TypographyVariant::Caption
+    | TypographyVariant::Overline
+    | TypographyVariant::Button => { "span" }
+  }
+}
+
+pub(crate) fn component_css() -> String {
+  String::from(include_str!("typography.scss"))
+}
+
+fn create_style(theme: &Theme) -> StyleScope {
+  get_style("typography", theme, &theme.components.typography.style_overrides, component_css)
+}
+
+impl Component for Typography {
+  type Message = Msg;
+  type Properties = Props;
+
+  fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+    Self {
+      style: ComponentStyle::new(&link, link.callback(Msg::SetTheme), "typography", create_style, &props.sx),
+      color_style: get_color_style(&props.color),
+      props,
+    }
+  }
+
+  fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+      Msg::SetTheme(theme) => {
+        self.style.set_theme(theme, &self.props.sx);
+        true
+      }
+    }
+  }
+
+  fn change(&mut self, props: Self::Properties) -> ShouldRender {
+    if self.props.color != props.color {
+      self.color_style = get_color_style(&props.color);
+    }
+
+    self.style.change(&mut self.props, props, |props| &mut props.sx)
+  }
+
+  fn view(&self) -> Html {
+    let class = self.format_classes();
+    let component = get_element(&self.props.variant);
+
+    html! {
+      <@{component}
+        class=class
+        style=self.color_style.clone()
+      >
+        {self.props.children.clone()}
+      </@>
+    }
+  }
+}
+
+impl Typography {
+  fn format_classes(&self) -> Classes {
+    let mut classes = self.style.classes(&self.props.class);
+
+    classes.push(self.style.modifier(&get_variant_class(&self.props.variant)));
+
+    if let Some(color) = get_color_class(&self.props.color) {
+      classes.push(self.style.modifier(&color));
+    }
+    if let Some(align) = get_align_class(&self.props.align) {
+      classes.push(self.style.modifier(&align));
+    }
+    if self.props.no_wrap {
+      classes.push(self.style.modifier("no-wrap"));
+    }
+    if self.props.gutter_bottom {
+      classes.push(self.style.modifier("gutter-bottom"));
+    }
+
+    classes
+  }
+}
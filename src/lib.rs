@@ -1,7 +1,14 @@
-mod theme;
+#![allow(clippy::module_inception)]
+
+pub mod theme;
+pub mod ssr;
 mod components;
 
 pub use components::button;
 pub use components::menu_item;
 pub use components::button_icon;
-pub use components::table;
\ No newline at end of file
+pub use components::button_group;
+pub use components::icon_button;
+pub use components::table;
+pub use components::theme_provider;
+pub use components::typography;
\ No newline at end of file
@@ -0,0 +1,145 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::theme::Theme;
+use crate::components::component_styles;
+use crate::components::css_scanner::{split_items, split_selectors, replace_parent, CssItem};
+
+/// Scopes a component style sheet under `.class`, the way css-in-rust does
+/// at runtime: `&` stands for the class, other selectors are nested in it
+/// and bare declarations apply to it.
+fn scope_css(css: &str, class: &str) -> String {
+  let mut rules = vec![];
+  let mut declarations = vec![];
+
+  for item in split_items(css) {
+    match item {
+      CssItem::Declaration(declaration) => {
+        let declaration = declaration.trim().trim_end_matches(';').trim_end();
+
+        if !declaration.is_empty() {
+          declarations.push(format!("  {};", declaration));
+        }
+      }
+      CssItem::Rule(prelude, body) => { rules.push(scope_rule(prelude.trim(), body, class)) }
+    }
+  }
+
+  if !declarations.is_empty() {
+    rules.insert(0, format!(".{} {{\n{}\n}}", class, declarations.join("\n")));
+  }
+
+  rules.join("\n\n")
+}
+
+fn scope_rule(prelude: &str, body: &str, class: &str) -> String {
+  if prelude.starts_with("@media") || prelude.starts_with("@supports") {
+    format!("{} {{\n{}\n}}", prelude, scope_css(body, class))
+  } else if prelude.starts_with('@') {
+    format!("{} {{{}}}", prelude, body)
+  } else {
+    let scope = format!(".{}", class);
+    let selectors = split_selectors(prelude).into_iter()
+      .map(|selector| {
+        let selector = selector.trim();
+
+        replace_parent(selector, &scope).unwrap_or_else(|| format!("{} {}", scope, selector))
+      })
+      .collect::<Vec<String>>()
+      .join(", ");
+
+    format!("{} {{{}\n}}", selectors, body.trim_end())
+  }
+}
+
+/// Returns the style sheets of every component for `theme`, and the theme
+/// variables as `:root` and `dao-theme-provider` rules, as a single static
+/// style sheet.
+///
+/// Serve it with server rendered pages and render them in a `ThemeProvider`
+/// whose theme has `static_styles` set: components then use the stable class
+/// names of the sheet instead of generating their own at runtime, so the page
+/// is styled from the first paint. Per instance `sx` props are still created
+/// at runtime.
+pub fn extract_css(theme: &Theme) -> String {
+  let mut css = theme.to_root_css();
+
+  for (class, source) in component_styles(theme) {
+    css.push('\n');
+    css.push_str(&scope_css(&source, &class));
+    css.push('\n');
+  }
+
+  css
+}
+
+/// Writes `extract_css(theme)` to `path`, e.g. from a `build.rs`:
+///
+/// ```no_run
+/// let theme = dao_ui::theme::Theme::default();
+///
+/// dao_ui::ssr::write_css(&theme, "static/dao-ui.css").unwrap();
+/// ```
+pub fn write_css<P: AsRef<Path>>(theme: &Theme, path: P) -> io::Result<()> {
+  fs::write(path, extract_css(theme))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::scope_css;
+
+  #[test]
+  fn scopes_selectors() {
+    assert_eq!(
+      scope_css("&.loading .content {\n  visibility: hidden;\n}", "dao-button"),
+      ".dao-button.loading .content {\n  visibility: hidden;\n}",
+    );
+    assert_eq!(
+      scope_css(".icon, & > svg {\n  width: 1em;\n}", "dao-button"),
+      ".dao-button .icon, .dao-button > svg {\n  width: 1em;\n}",
+    );
+  }
+
+  #[test]
+  fn keeps_selector_lists_in_parentheses() {
+    assert_eq!(
+      scope_css(":not(.a, .b), &:is(.c, .d) {\n  margin: 0;\n}", "dao-button"),
+      ".dao-button :not(.a, .b), .dao-button:is(.c, .d) {\n  margin: 0;\n}",
+    );
+  }
+
+  #[test]
+  fn keeps_strings_and_urls() {
+    assert_eq!(
+      scope_css("&::after {\n  content: \"}\";\n}\n.icon {\n  margin: 0;\n}", "dao-button"),
+      ".dao-button::after {\n  content: \"}\";\n}\n\n.dao-button .icon {\n  margin: 0;\n}",
+    );
+    assert_eq!(
+      scope_css("background: url(data:image/png;base64,AA==);\n&[title=\"a&b\"] {\n  margin: 0;\n}", "dao-button"),
+      ".dao-button {\n  background: url(data:image/png;base64,AA==);\n}\n\n.dao-button[title=\"a&b\"] {\n  margin: 0;\n}",
+    );
+  }
+
+  #[test]
+  fn scopes_bare_declarations() {
+    assert_eq!(
+      scope_css("color: red;\n& {\n  margin: 0;\n}", "dao-button"),
+      ".dao-button {\n  color: red;\n}\n\n.dao-button {\n  margin: 0;\n}",
+    );
+  }
+
+  #[test]
+  fn scopes_media_rules() {
+    assert_eq!(
+      scope_css("@media (min-width: 768px) {\n  &.fullwidth {\n    width: 100%;\n  }\n}", "dao-button"),
+      "@media (min-width: 768px) {\n.dao-button.fullwidth {\n    width: 100%;\n}\n}",
+    );
+  }
+
+  #[test]
+  fn keeps_keyframes() {
+    let keyframes = "@keyframes rotate {\n  100% {\n    transform: rotate(360deg);\n  }\n}";
+
+    assert_eq!(scope_css(keyframes, "dao-button"), keyframes);
+  }
+}
@@ -0,0 +1,108 @@
+use super::{Color, RGB};
+
+/// Separable blend modes of the W3C Compositing and Blending spec.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BlendMode {
+  Multiply,
+  Screen,
+  Overlay,
+  Darken,
+  Lighten,
+  ColorDodge,
+  ColorBurn,
+  HardLight,
+  SoftLight,
+  Difference,
+  Exclusion,
+}
+
+fn multiply(cb: f64, cs: f64) -> f64 {
+  cb * cs
+}
+
+fn screen(cb: f64, cs: f64) -> f64 {
+  cb + cs - cb * cs
+}
+
+fn hard_light(cb: f64, cs: f64) -> f64 {
+  if cs <= 0.5 {
+    multiply(cb, 2.0 * cs)
+  } else {
+    screen(cb, 2.0 * cs - 1.0)
+  }
+}
+
+fn soft_light(cb: f64, cs: f64) -> f64 {
+  if cs <= 0.5 {
+    cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+  } else {
+    let d = if cb <= 0.25 {
+      ((16.0 * cb - 12.0) * cb + 4.0) * cb
+    } else {
+      cb.sqrt()
+    };
+
+    cb + (2.0 * cs - 1.0) * (d - cb)
+  }
+}
+
+fn blend_channel(mode: BlendMode, cb: f64, cs: f64) -> f64 {
+  match mode {
+    BlendMode::Multiply => { multiply(cb, cs) }
+    BlendMode::Screen => { screen(cb, cs) }
+    BlendMode::Overlay => { hard_light(cs, cb) }
+    BlendMode::Darken => { cb.min(cs) }
+    BlendMode::Lighten => { cb.max(cs) }
+    BlendMode::ColorDodge => {
+      if cb == 0.0 {
+        0.0
+      } else if cs >= 1.0 {
+        1.0
+      } else {
+        (cb / (1.0 - cs)).min(1.0)
+      }
+    }
+    BlendMode::ColorBurn => {
+      if cb >= 1.0 {
+        1.0
+      } else if cs == 0.0 {
+        0.0
+      } else {
+        1.0 - ((1.0 - cb) / cs).min(1.0)
+      }
+    }
+    BlendMode::HardLight => { hard_light(cb, cs) }
+    BlendMode::SoftLight => { soft_light(cb, cs) }
+    BlendMode::Difference => { (cb - cs).abs() }
+    BlendMode::Exclusion => { cb + cs - 2.0 * cb * cs }
+  }
+}
+
+/// Blends `color_2` (source) over `color_1` (backdrop) with the given mode.
+pub fn blend_color(
+  mode: BlendMode,
+  color_1: Color,
+  color_2: Color,
+) -> Color {
+  let ab = color_1.alpha;
+  let a_s = color_2.alpha;
+  let ar = a_s + ab * (1.0 - a_s);
+
+  let color_1_rgb = [color_1.rgb.0, color_1.rgb.1, color_1.rgb.2];
+  let color_2_rgb = [color_2.rgb.0, color_2.rgb.1, color_2.rgb.2];
+  let mut result_rgb = [0; 3];
+
+  for i in 0..3 {
+    let cb = color_1_rgb[i] as f64 / 255.0;
+    let cs = color_2_rgb[i] as f64 / 255.0;
+    let mut cr = blend_channel(mode, cb, cs);
+
+    if ar != 0.0 {
+      cr = (a_s * cs + ab * (cb - a_s * (cb + cs - cr))) / ar;
+    }
+
+    result_rgb[i] = (cr * 255.0) as i32;
+  }
+
+  Color::new(RGB(result_rgb[0], result_rgb[1], result_rgb[2]), ar)
+}
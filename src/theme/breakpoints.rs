@@ -0,0 +1,96 @@
+use super::Breakpoints;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Breakpoint {
+  Xs,
+  Sm,
+  Md,
+  Lg,
+  Xl,
+}
+
+/// Every breakpoint, from the smallest to the largest.
+pub const BREAKPOINTS: [Breakpoint; 5] = [
+  Breakpoint::Xs,
+  Breakpoint::Sm,
+  Breakpoint::Md,
+  Breakpoint::Lg,
+  Breakpoint::Xl,
+];
+
+impl Breakpoint {
+  pub fn name(self) -> &'static str {
+    match self {
+      Breakpoint::Xs => { "xs" }
+      Breakpoint::Sm => { "sm" }
+      Breakpoint::Md => { "md" }
+      Breakpoint::Lg => { "lg" }
+      Breakpoint::Xl => { "xl" }
+    }
+  }
+}
+
+impl Breakpoints {
+  /// Returns the min width of `breakpoint`, in pixels.
+  pub fn width(&self, breakpoint: Breakpoint) -> i32 {
+    match breakpoint {
+      Breakpoint::Xs => { self.xs }
+      Breakpoint::Sm => { self.sm }
+      Breakpoint::Md => { self.md }
+      Breakpoint::Lg => { self.lg }
+      Breakpoint::Xl => { self.xl }
+    }
+  }
+
+  /// Returns every breakpoint with its min width, from the smallest to the largest.
+  pub fn values(&self) -> Vec<(Breakpoint, i32)> {
+    BREAKPOINTS.iter().map(|&b| (b, self.width(b))).collect()
+  }
+
+  /// Returns the breakpoint a viewport of `width` pixels falls into.
+  pub fn current(&self, width: f64) -> Breakpoint {
+    self.values()
+      .into_iter()
+      .rev()
+      .find(|&(_, min)| width >= min as f64)
+      .map(|(b, _)| b)
+      .unwrap_or(Breakpoint::Xs)
+  }
+
+  /// Media condition matching `breakpoint` and every larger one, without
+  /// the `@media` keyword, as `window.matchMedia` expects it.
+  pub fn up_query(&self, breakpoint: Breakpoint) -> String {
+    format!("(min-width: {}px)", self.width(breakpoint))
+  }
+
+  /// Media condition matching every breakpoint smaller than `breakpoint`.
+  /// Nothing is smaller than `Xs`, so its query never matches.
+  pub fn down_query(&self, breakpoint: Breakpoint) -> String {
+    if breakpoint == Breakpoint::Xs {
+      return String::from("not all");
+    }
+
+    // Keeps the ranges of `up` and `down` from overlapping on fractional widths.
+    format!("(max-width: {}px)", self.width(breakpoint) as f64 - 0.02)
+  }
+
+  /// `@media` query for `breakpoint` and larger, e.g. `up(Breakpoint::Md)`.
+  pub fn up(&self, breakpoint: Breakpoint) -> String {
+    format!("@media {}", self.up_query(breakpoint))
+  }
+
+  /// `@media` query for screens smaller than `breakpoint`.
+  pub fn down(&self, breakpoint: Breakpoint) -> String {
+    format!("@media {}", self.down_query(breakpoint))
+  }
+
+  /// `@media` query from `start` up to, but not including, `end`.
+  pub fn between(&self, start: Breakpoint, end: Breakpoint) -> String {
+    // `not all` can't be combined with `and`.
+    if end == Breakpoint::Xs {
+      return self.down(end);
+    }
+
+    format!("@media {} and {}", self.up_query(start), self.down_query(end))
+  }
+}
@@ -0,0 +1,501 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(clippy::upper_case_acronyms)]
+pub struct RGB(pub i32, pub i32, pub i32);
+
+/// Hue in degrees (`0..360`), saturation and lightness in percents (`0..=100`).
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(clippy::upper_case_acronyms)]
+pub struct HSL(pub f64, pub f64, pub f64);
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Color {
+  pub alpha: f64,
+  pub rgb: RGB,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ParseColorError {
+  value: String,
+}
+
+impl fmt::Display for ParseColorError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "invalid color \"{}\"", self.value)
+  }
+}
+
+impl std::error::Error for ParseColorError {}
+
+const NAMED_COLORS: [(&str, RGB); 148] = [
+  ("aliceblue", RGB(240, 248, 255)),
+  ("antiquewhite", RGB(250, 235, 215)),
+  ("aqua", RGB(0, 255, 255)),
+  ("aquamarine", RGB(127, 255, 212)),
+  ("azure", RGB(240, 255, 255)),
+  ("beige", RGB(245, 245, 220)),
+  ("bisque", RGB(255, 228, 196)),
+  ("black", RGB(0, 0, 0)),
+  ("blanchedalmond", RGB(255, 235, 205)),
+  ("blue", RGB(0, 0, 255)),
+  ("blueviolet", RGB(138, 43, 226)),
+  ("brown", RGB(165, 42, 42)),
+  ("burlywood", RGB(222, 184, 135)),
+  ("cadetblue", RGB(95, 158, 160)),
+  ("chartreuse", RGB(127, 255, 0)),
+  ("chocolate", RGB(210, 105, 30)),
+  ("coral", RGB(255, 127, 80)),
+  ("cornflowerblue", RGB(100, 149, 237)),
+  ("cornsilk", RGB(255, 248, 220)),
+  ("crimson", RGB(220, 20, 60)),
+  ("cyan", RGB(0, 255, 255)),
+  ("darkblue", RGB(0, 0, 139)),
+  ("darkcyan", RGB(0, 139, 139)),
+  ("darkgoldenrod", RGB(184, 134, 11)),
+  ("darkgray", RGB(169, 169, 169)),
+  ("darkgreen", RGB(0, 100, 0)),
+  ("darkgrey", RGB(169, 169, 169)),
+  ("darkkhaki", RGB(189, 183, 107)),
+  ("darkmagenta", RGB(139, 0, 139)),
+  ("darkolivegreen", RGB(85, 107, 47)),
+  ("darkorange", RGB(255, 140, 0)),
+  ("darkorchid", RGB(153, 50, 204)),
+  ("darkred", RGB(139, 0, 0)),
+  ("darksalmon", RGB(233, 150, 122)),
+  ("darkseagreen", RGB(143, 188, 143)),
+  ("darkslateblue", RGB(72, 61, 139)),
+  ("darkslategray", RGB(47, 79, 79)),
+  ("darkslategrey", RGB(47, 79, 79)),
+  ("darkturquoise", RGB(0, 206, 209)),
+  ("darkviolet", RGB(148, 0, 211)),
+  ("deeppink", RGB(255, 20, 147)),
+  ("deepskyblue", RGB(0, 191, 255)),
+  ("dimgray", RGB(105, 105, 105)),
+  ("dimgrey", RGB(105, 105, 105)),
+  ("dodgerblue", RGB(30, 144, 255)),
+  ("firebrick", RGB(178, 34, 34)),
+  ("floralwhite", RGB(255, 250, 240)),
+  ("forestgreen", RGB(34, 139, 34)),
+  ("fuchsia", RGB(255, 0, 255)),
+  ("gainsboro", RGB(220, 220, 220)),
+  ("ghostwhite", RGB(248, 248, 255)),
+  ("gold", RGB(255, 215, 0)),
+  ("goldenrod", RGB(218, 165, 32)),
+  ("gray", RGB(128, 128, 128)),
+  ("green", RGB(0, 128, 0)),
+  ("greenyellow", RGB(173, 255, 47)),
+  ("grey", RGB(128, 128, 128)),
+  ("honeydew", RGB(240, 255, 240)),
+  ("hotpink", RGB(255, 105, 180)),
+  ("indianred", RGB(205, 92, 92)),
+  ("indigo", RGB(75, 0, 130)),
+  ("ivory", RGB(255, 255, 240)),
+  ("khaki", RGB(240, 230, 140)),
+  ("lavender", RGB(230, 230, 250)),
+  ("lavenderblush", RGB(255, 240, 245)),
+  ("lawngreen", RGB(124, 252, 0)),
+  ("lemonchiffon", RGB(255, 250, 205)),
+  ("lightblue", RGB(173, 216, 230)),
+  ("lightcoral", RGB(240, 128, 128)),
+  ("lightcyan", RGB(224, 255, 255)),
+  ("lightgoldenrodyellow", RGB(250, 250, 210)),
+  ("lightgray", RGB(211, 211, 211)),
+  ("lightgreen", RGB(144, 238, 144)),
+  ("lightgrey", RGB(211, 211, 211)),
+  ("lightpink", RGB(255, 182, 193)),
+  ("lightsalmon", RGB(255, 160, 122)),
+  ("lightseagreen", RGB(32, 178, 170)),
+  ("lightskyblue", RGB(135, 206, 250)),
+  ("lightslategray", RGB(119, 136, 153)),
+  ("lightslategrey", RGB(119, 136, 153)),
+  ("lightsteelblue", RGB(176, 196, 222)),
+  ("lightyellow", RGB(255, 255, 224)),
+  ("lime", RGB(0, 255, 0)),
+  ("limegreen", RGB(50, 205, 50)),
+  ("linen", RGB(250, 240, 230)),
+  ("magenta", RGB(255, 0, 255)),
+  ("maroon", RGB(128, 0, 0)),
+  ("mediumaquamarine", RGB(102, 205, 170)),
+  ("mediumblue", RGB(0, 0, 205)),
+  ("mediumorchid", RGB(186, 85, 211)),
+  ("mediumpurple", RGB(147, 112, 219)),
+  ("mediumseagreen", RGB(60, 179, 113)),
+  ("mediumslateblue", RGB(123, 104, 238)),
+  ("mediumspringgreen", RGB(0, 250, 154)),
+  ("mediumturquoise", RGB(72, 209, 204)),
+  ("mediumvioletred", RGB(199, 21, 133)),
+  ("midnightblue", RGB(25, 25, 112)),
+  ("mintcream", RGB(245, 255, 250)),
+  ("mistyrose", RGB(255, 228, 225)),
+  ("moccasin", RGB(255, 228, 181)),
+  ("navajowhite", RGB(255, 222, 173)),
+  ("navy", RGB(0, 0, 128)),
+  ("oldlace", RGB(253, 245, 230)),
+  ("olive", RGB(128, 128, 0)),
+  ("olivedrab", RGB(107, 142, 35)),
+  ("orange", RGB(255, 165, 0)),
+  ("orangered", RGB(255, 69, 0)),
+  ("orchid", RGB(218, 112, 214)),
+  ("palegoldenrod", RGB(238, 232, 170)),
+  ("palegreen", RGB(152, 251, 152)),
+  ("paleturquoise", RGB(175, 238, 238)),
+  ("palevioletred", RGB(219, 112, 147)),
+  ("papayawhip", RGB(255, 239, 213)),
+  ("peachpuff", RGB(255, 218, 185)),
+  ("peru", RGB(205, 133, 63)),
+  ("pink", RGB(255, 192, 203)),
+  ("plum", RGB(221, 160, 221)),
+  ("powderblue", RGB(176, 224, 230)),
+  ("purple", RGB(128, 0, 128)),
+  ("rebeccapurple", RGB(102, 51, 153)),
+  ("red", RGB(255, 0, 0)),
+  ("rosybrown", RGB(188, 143, 143)),
+  ("royalblue", RGB(65, 105, 225)),
+  ("saddlebrown", RGB(139, 69, 19)),
+  ("salmon", RGB(250, 128, 114)),
+  ("sandybrown", RGB(244, 164, 96)),
+  ("seagreen", RGB(46, 139, 87)),
+  ("seashell", RGB(255, 245, 238)),
+  ("sienna", RGB(160, 82, 45)),
+  ("silver", RGB(192, 192, 192)),
+  ("skyblue", RGB(135, 206, 235)),
+  ("slateblue", RGB(106, 90, 205)),
+  ("slategray", RGB(112, 128, 144)),
+  ("slategrey", RGB(112, 128, 144)),
+  ("snow", RGB(255, 250, 250)),
+  ("springgreen", RGB(0, 255, 127)),
+  ("steelblue", RGB(70, 130, 180)),
+  ("tan", RGB(210, 180, 140)),
+  ("teal", RGB(0, 128, 128)),
+  ("thistle", RGB(216, 191, 216)),
+  ("tomato", RGB(255, 99, 71)),
+  ("turquoise", RGB(64, 224, 208)),
+  ("violet", RGB(238, 130, 238)),
+  ("wheat", RGB(245, 222, 179)),
+  ("white", RGB(255, 255, 255)),
+  ("whitesmoke", RGB(245, 245, 245)),
+  ("yellow", RGB(255, 255, 0)),
+  ("yellowgreen", RGB(154, 205, 50)),
+];
+
+fn clamp_channel(v: f64) -> i32 {
+  v.round().clamp(0.0, 255.0) as i32
+}
+
+fn clamp_unit(v: f64) -> f64 {
+  v.clamp(0.0, 1.0)
+}
+
+fn parse_number(value: &str) -> Option<f64> {
+  let n: f64 = value.parse().ok()?;
+
+  if n.is_finite() { Some(n) } else { None }
+}
+
+fn parse_in_range(value: &str, max: f64) -> Option<f64> {
+  let n = parse_number(value)?;
+
+  if (0.0..=max).contains(&n) { Some(n) } else { None }
+}
+
+/// `255`, `100%` -> `0..=255`
+fn parse_channel(value: &str) -> Option<i32> {
+  match value.strip_suffix('%') {
+    Some(percent) => { Some(clamp_channel(parse_in_range(percent, 100.0)? / 100.0 * 255.0)) }
+    None => { Some(clamp_channel(parse_in_range(value, 255.0)?)) }
+  }
+}
+
+/// `0.5`, `50%` -> `0..=1`
+fn parse_alpha(value: &str) -> Option<f64> {
+  match value.strip_suffix('%') {
+    Some(percent) => { Some(parse_in_range(percent, 100.0)? / 100.0) }
+    None => { parse_in_range(value, 1.0) }
+  }
+}
+
+/// `120`, `120deg`, `0.5turn`, `2rad` -> degrees
+fn parse_hue(value: &str) -> Option<f64> {
+  let degrees = if let Some(v) = value.strip_suffix("deg") {
+    parse_number(v)?
+  } else if let Some(v) = value.strip_suffix("turn") {
+    parse_number(v)? * 360.0
+  } else if let Some(v) = value.strip_suffix("rad") {
+    parse_number(v)?.to_degrees()
+  } else {
+    parse_number(value)?
+  };
+
+  Some(degrees.rem_euclid(360.0))
+}
+
+/// `50%` -> `0..=100`
+fn parse_percent(value: &str) -> Option<f64> {
+  parse_in_range(value.strip_suffix('%').unwrap_or(value), 100.0)
+}
+
+/// Splits the arguments of `rgb()`/`hsl()`, both in the legacy comma
+/// separated and in the space separated syntax.
+fn split_arguments(args: &str) -> Option<(Vec<&str>, Option<&str>)> {
+  if args.contains(',') {
+    let parts: Vec<&str> = args.split(',').map(str::trim).collect();
+
+    match parts.len() {
+      3 => { Some((parts, None)) }
+      4 => { Some((parts[..3].to_vec(), Some(parts[3]))) }
+      _ => { None }
+    }
+  } else {
+    let mut halves = args.splitn(2, '/');
+    let parts: Vec<&str> = halves.next()?.split_whitespace().collect();
+    let alpha = halves.next().map(str::trim);
+
+    if parts.len() == 3 {
+      Some((parts, alpha))
+    } else {
+      None
+    }
+  }
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+  if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+    return None;
+  }
+
+  let digits: Vec<i32> = match hex.len() {
+    3 | 4 => {
+      hex.chars()
+        .map(|c| c.to_digit(16).map(|d| (d * 17) as i32))
+        .collect::<Option<Vec<i32>>>()?
+    }
+    6 | 8 => {
+      (0..hex.len()).step_by(2)
+        .map(|i| i32::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect::<Option<Vec<i32>>>()?
+    }
+    _ => { return None }
+  };
+  let alpha = digits.get(3).map(|a| *a as f64 / 255.0).unwrap_or(1.0);
+
+  Some(Color::new(RGB(digits[0], digits[1], digits[2]), alpha))
+}
+
+fn parse_function(name: &str, args: &str) -> Option<Color> {
+  let (parts, alpha) = split_arguments(args)?;
+  let alpha = match alpha {
+    Some(a) => { parse_alpha(a)? }
+    None => { 1.0 }
+  };
+
+  match name {
+    "rgb" | "rgba" => {
+      Some(Color::new(
+        RGB(parse_channel(parts[0])?, parse_channel(parts[1])?, parse_channel(parts[2])?),
+        alpha,
+      ))
+    }
+    "hsl" | "hsla" => {
+      Some(Color::from_hsl(
+        HSL(parse_hue(parts[0])?, parse_percent(parts[1])?, parse_percent(parts[2])?),
+        alpha,
+      ))
+    }
+    _ => { None }
+  }
+}
+
+impl Color {
+  pub fn new(rgb: RGB, alpha: f64) -> Self {
+    Color {
+      alpha: clamp_unit(alpha),
+      rgb: RGB(
+        clamp_channel(rgb.0 as f64),
+        clamp_channel(rgb.1 as f64),
+        clamp_channel(rgb.2 as f64),
+      ),
+    }
+  }
+
+  pub fn from_rgb(red: i32, green: i32, blue: i32) -> Self {
+    Color::new(RGB(red, green, blue), 1.0)
+  }
+
+  pub fn from_hsl(hsl: HSL, alpha: f64) -> Self {
+    let h = hsl.0.rem_euclid(360.0) / 360.0;
+    let s = clamp_unit(hsl.1 / 100.0);
+    let l = clamp_unit(hsl.2 / 100.0);
+    let q = if l < 0.5 { l * (1.0 + s) } else { l + s - l * s };
+    let p = 2.0 * l - q;
+    let hue_to_rgb = |t: f64| {
+      let t = t.rem_euclid(1.0);
+
+      if t < 1.0 / 6.0 {
+        p + (q - p) * 6.0 * t
+      } else if t < 1.0 / 2.0 {
+        q
+      } else if t < 2.0 / 3.0 {
+        p + (q - p) * (2.0 / 3.0 - t) * 6.0
+      } else {
+        p
+      }
+    };
+
+    Color::new(
+      RGB(
+        clamp_channel(hue_to_rgb(h + 1.0 / 3.0) * 255.0),
+        clamp_channel(hue_to_rgb(h) * 255.0),
+        clamp_channel(hue_to_rgb(h - 1.0 / 3.0) * 255.0),
+      ),
+      alpha,
+    )
+  }
+
+  /// Parses any CSS color: hex (`#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`),
+  /// `rgb()`/`rgba()`, `hsl()`/`hsla()`, named colors and `transparent`.
+  /// Channels, percentages and alpha values out of their range are
+  /// rejected rather than clamped, they are most likely typos.
+  pub fn parse(value: &str) -> Result<Self, ParseColorError> {
+    let normalized = value.trim().to_ascii_lowercase();
+    let color = if let Some(hex) = normalized.strip_prefix('#') {
+      parse_hex(hex)
+    } else if let (Some(open), true) = (normalized.find('('), normalized.ends_with(')')) {
+      parse_function(normalized[..open].trim(), &normalized[open + 1..normalized.len() - 1])
+    } else if normalized == "transparent" {
+      Some(Color::new(RGB(0, 0, 0), 0.0))
+    } else {
+      NAMED_COLORS.iter()
+        .find(|(name, _)| *name == normalized)
+        .map(|(_, rgb)| Color::new(*rgb, 1.0))
+    };
+
+    color.ok_or_else(|| ParseColorError { value: String::from(value) })
+  }
+
+  pub fn hsl(&self) -> HSL {
+    let r = self.rgb.0 as f64 / 255.0;
+    let g = self.rgb.1 as f64 / 255.0;
+    let b = self.rgb.2 as f64 / 255.0;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let d = max - min;
+
+    if d == 0.0 {
+      return HSL(0.0, 0.0, l * 100.0);
+    }
+
+    let s = if l > 0.5 { d / (2.0 - max - min) } else { d / (max + min) };
+    let h = if max == r {
+      (g - b) / d + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+      (b - r) / d + 2.0
+    } else {
+      (r - g) / d + 4.0
+    };
+
+    HSL(h * 60.0, s * 100.0, l * 100.0)
+  }
+
+  /// WCAG relative luminance, from `0.0` (black) to `1.0` (white).
+  /// The alpha channel is ignored.
+  pub fn luminance(&self) -> f64 {
+    let linear = |c: i32| {
+      let c = c as f64 / 255.0;
+
+      if c <= 0.03928 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+    };
+
+    0.2126 * linear(self.rgb.0) + 0.7152 * linear(self.rgb.1) + 0.0722 * linear(self.rgb.2)
+  }
+
+  /// WCAG contrast ratio between two colors, from `1.0` to `21.0`.
+  pub fn contrast_ratio(&self, other: &Color) -> f64 {
+    let l1 = self.luminance();
+    let l2 = other.luminance();
+
+    (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+  }
+
+  /// Returns the color as `#rrggbb`, ignoring the alpha channel.
+  pub fn to_hex(&self) -> String {
+    let RGB(r, g, b) = self.clamped_rgb();
+
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+  }
+
+  /// Returns `#rrggbb` for opaque colors and `rgba()` otherwise.
+  pub fn to_css_string(&self) -> String {
+    let alpha = clamp_unit(self.alpha);
+
+    if alpha >= 1.0 {
+      self.to_hex()
+    } else {
+      let RGB(r, g, b) = self.clamped_rgb();
+      let alpha = (alpha * 1000.0).round() / 1000.0;
+
+      format!("rgba({}, {}, {}, {})", r, g, b, alpha)
+    }
+  }
+
+  /// The fields are public, so channels set directly may be out of range.
+  fn clamped_rgb(&self) -> RGB {
+    RGB(
+      self.rgb.0.clamp(0, 255),
+      self.rgb.1.clamp(0, 255),
+      self.rgb.2.clamp(0, 255),
+    )
+  }
+
+  /// Increases the HSL lightness by `amount` (`0.1` is 10%).
+  pub fn lighten(&self, amount: f64) -> Self {
+    let HSL(h, s, l) = self.hsl();
+
+    Color::from_hsl(HSL(h, s, l + amount * 100.0), self.alpha)
+  }
+
+  /// Decreases the HSL lightness by `amount` (`0.1` is 10%).
+  pub fn darken(&self, amount: f64) -> Self {
+    self.lighten(-amount)
+  }
+
+  /// Returns the same color with the given opacity.
+  pub fn alpha(&self, alpha: f64) -> Self {
+    Color::new(self.rgb, alpha)
+  }
+
+  /// Mixes two colors the way Sass `mix()` does. `weight` is the share of
+  /// `self` in the result, from `0.0` to `1.0`.
+  pub fn mix(&self, other: &Color, weight: f64) -> Self {
+    let weight = clamp_unit(weight);
+    let w = 2.0 * weight - 1.0;
+    let a = self.alpha - other.alpha;
+    let w1 = (if w * a == -1.0 { w } else { (w + a) / (1.0 + w * a) } + 1.0) / 2.0;
+    let w2 = 1.0 - w1;
+
+    Color::new(
+      RGB(
+        clamp_channel(self.rgb.0 as f64 * w1 + other.rgb.0 as f64 * w2),
+        clamp_channel(self.rgb.1 as f64 * w1 + other.rgb.1 as f64 * w2),
+        clamp_channel(self.rgb.2 as f64 * w1 + other.rgb.2 as f64 * w2),
+      ),
+      self.alpha * weight + other.alpha * (1.0 - weight),
+    )
+  }
+}
+
+impl FromStr for Color {
+  type Err = ParseColorError;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    Color::parse(value)
+  }
+}
+
+impl fmt::Display for Color {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.to_css_string())
+  }
+}
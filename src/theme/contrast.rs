@@ -0,0 +1,138 @@
+use super::{Theme, Palette, PaletteColor, Color, RGB, SHADE_STEPS};
+
+/// Minimum contrast ratio for normal text to pass WCAG AA.
+pub const AA_CONTRAST_RATIO: f64 = 4.5;
+/// Minimum contrast ratio for large text to pass WCAG AA.
+pub const AA_LARGE_CONTRAST_RATIO: f64 = 3.0;
+
+const LIGHT_TEXT: Color = Color { alpha: 1.0, rgb: RGB(255, 255, 255) };
+const DARK_TEXT: Color = Color { alpha: 1.0, rgb: RGB(0, 0, 0) };
+
+/// A foreground/background pairing of the palette that falls below
+/// `AA_CONTRAST_RATIO`. Both colors are given as theme paths,
+/// e.g. `palette.primary.main`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ContrastIssue {
+  pub foreground: String,
+  pub background: String,
+  pub ratio: f64,
+}
+
+/// Returns the text color to use on `background`: white or black,
+/// whichever has the higher contrast ratio.
+pub fn contrast_text(background: &Color) -> Color {
+  if LIGHT_TEXT.contrast_ratio(background) >= DARK_TEXT.contrast_ratio(background) {
+    LIGHT_TEXT
+  } else {
+    DARK_TEXT
+  }
+}
+
+/// Lowest contrast ratio of `color` against any of `backgrounds`.
+fn min_contrast_ratio(color: &Color, backgrounds: &[Color]) -> f64 {
+  backgrounds.iter()
+    .map(|background| color.contrast_ratio(background))
+    .fold(f64::INFINITY, f64::min)
+}
+
+impl PaletteColor {
+  /// Returns the step of the shade to use for text in this color on
+  /// `backgrounds`, or `None` when `main` is readable as it is. Shades are
+  /// tried from `main` towards the end of the scale that contrasts with the
+  /// backgrounds; the first one passing `AA_CONTRAST_RATIO` is used, or the
+  /// most contrasting one when none does.
+  pub fn text_step(&self, backgrounds: &[Color]) -> Option<u16> {
+    let main = Color::parse(&self.main).ok()?;
+    let main_ratio = min_contrast_ratio(&main, backgrounds);
+
+    if main_ratio >= AA_CONTRAST_RATIO || backgrounds.is_empty() {
+      return None;
+    }
+
+    let darker = backgrounds.iter().all(|b| b.luminance() > main.luminance());
+    let steps: Vec<u16> = if darker {
+      SHADE_STEPS.iter().cloned().filter(|s| *s > 500).collect()
+    } else {
+      SHADE_STEPS.iter().rev().cloned().filter(|s| *s < 500).collect()
+    };
+    let mut best = (None, main_ratio);
+
+    for step in steps {
+      let ratio = match self.shade(step).map(Color::parse) {
+        Some(Ok(shade)) => { min_contrast_ratio(&shade, backgrounds) }
+        _ => { continue }
+      };
+
+      if ratio >= AA_CONTRAST_RATIO {
+        return Some(step);
+      }
+      if ratio > best.1 {
+        best = (Some(step), ratio);
+      }
+    }
+
+    best.0
+  }
+
+  /// Returns the color to use for text in this color on `backgrounds`,
+  /// see `text_step`.
+  pub fn text_on(&self, backgrounds: &[Color]) -> &str {
+    self.text_step(backgrounds)
+      .and_then(|step| self.shade(step))
+      .unwrap_or(&self.main)
+  }
+}
+
+impl Palette {
+  /// The backgrounds palette colors are written on as text: `background`
+  /// and `surface`, the ones that parse.
+  pub fn text_backgrounds(&self) -> Vec<Color> {
+    [&self.background, &self.surface].iter()
+      .filter_map(|value| Color::parse(value).ok())
+      .collect()
+  }
+}
+
+impl Theme {
+  /// Checks the contrast of every palette pairing used by the components
+  /// and returns the ones that fall below WCAG AA.
+  pub fn validate(&self) -> Vec<ContrastIssue> {
+    let palette = &self.palette;
+    let backgrounds = palette.text_backgrounds();
+    let mut pairs: Vec<(String, &str, String, &str)> = vec![];
+
+    for (name, color) in palette.colors() {
+      let path = palette.color_path(name);
+      let text = match color.text_step(&backgrounds) {
+        Some(step) => { format!("{}.shades.{}", path, step) }
+        None => { format!("{}.main", path) }
+      };
+
+      pairs.push((format!("{}.contrast_text", path), &color.contrast_text, format!("{}.main", path), &color.main));
+
+      for (background, value) in [("palette.background", &palette.background), ("palette.surface", &palette.surface)] {
+        pairs.push((text.clone(), color.text_on(&backgrounds), String::from(background), value));
+      }
+    }
+
+    for (foreground, value) in [
+      ("palette.text_primary", &palette.text_primary),
+      ("palette.text_secondary", &palette.text_secondary),
+    ] {
+      pairs.push((String::from(foreground), value, String::from("palette.background"), &palette.background));
+      pairs.push((String::from(foreground), value, String::from("palette.surface"), &palette.surface));
+    }
+
+    pairs.into_iter()
+      .filter_map(|(foreground, fg, background, bg)| {
+        let ratio = Color::parse(fg).ok()?.contrast_ratio(&Color::parse(bg).ok()?);
+
+        if ratio < AA_CONTRAST_RATIO {
+          Some(ContrastIssue { foreground, background, ratio })
+        } else {
+          None
+        }
+      })
+      .collect()
+  }
+}
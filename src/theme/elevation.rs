@@ -0,0 +1,55 @@
+use super::Color;
+
+/// Number of elevation levels, `0` (flat) to `24`.
+pub const ELEVATION_LEVELS: usize = 25;
+
+/// Umbra, penumbra and ambient layers of every elevation above `0`, as
+/// `(y offset, blur, spread)` in pixels.
+const SHADOW_LAYERS: [[(i32, i32, i32); 3]; ELEVATION_LEVELS - 1] = [
+  [(2, 1, -1), (1, 1, 0), (1, 3, 0)],
+  [(3, 1, -2), (2, 2, 0), (1, 5, 0)],
+  [(3, 3, -2), (3, 4, 0), (1, 8, 0)],
+  [(2, 4, -1), (4, 5, 0), (1, 10, 0)],
+  [(3, 5, -1), (5, 8, 0), (1, 14, 0)],
+  [(3, 5, -1), (6, 10, 0), (1, 18, 0)],
+  [(4, 5, -2), (7, 10, 1), (2, 16, 1)],
+  [(5, 5, -3), (8, 10, 1), (3, 14, 2)],
+  [(5, 6, -3), (9, 12, 1), (3, 16, 2)],
+  [(6, 6, -3), (10, 14, 1), (4, 18, 3)],
+  [(6, 7, -4), (11, 15, 1), (4, 20, 3)],
+  [(7, 8, -4), (12, 17, 2), (5, 22, 4)],
+  [(7, 8, -4), (13, 19, 2), (5, 24, 4)],
+  [(7, 9, -4), (14, 21, 2), (5, 26, 4)],
+  [(8, 9, -5), (15, 22, 2), (6, 28, 5)],
+  [(8, 10, -5), (16, 24, 2), (6, 30, 5)],
+  [(8, 11, -5), (17, 26, 2), (6, 32, 5)],
+  [(9, 11, -5), (18, 28, 2), (7, 34, 6)],
+  [(9, 12, -6), (19, 29, 2), (7, 36, 6)],
+  [(10, 13, -6), (20, 31, 3), (8, 38, 7)],
+  [(10, 13, -6), (21, 33, 3), (8, 40, 7)],
+  [(10, 14, -6), (22, 35, 3), (8, 42, 7)],
+  [(11, 14, -7), (23, 36, 3), (9, 44, 8)],
+  [(11, 15, -7), (24, 38, 3), (9, 46, 8)],
+];
+
+/// Builds the box-shadow of every elevation level. The opacities of the
+/// umbra, penumbra and ambient layers let light and dark palettes tune how
+/// strong the shadows are.
+pub fn create_shadows(umbra: f64, penumbra: f64, ambient: f64) -> Vec<String> {
+  let colors = [umbra, penumbra, ambient]
+    .iter()
+    .map(|&opacity| Color::from_rgb(0, 0, 0).alpha(opacity).to_css_string())
+    .collect::<Vec<String>>();
+
+  let mut shadows = vec![String::from("none")];
+
+  shadows.extend(SHADOW_LAYERS.iter().map(|layers| {
+    layers.iter()
+      .zip(&colors)
+      .map(|((y, blur, spread), color)| format!("0px {}px {}px {}px {}", y, blur, spread, color))
+      .collect::<Vec<String>>()
+      .join(", ")
+  }));
+
+  shadows
+}
@@ -0,0 +1,187 @@
+use std::fmt;
+use serde_json::Value;
+use super::{Theme, PaletteColor, Color, ELEVATION_LEVELS, generate_palette, is_valid_color_name};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThemeError {
+  /// The source could not be parsed or does not match the theme structure
+  /// (unknown keys, wrong value types, ...).
+  Parse(String),
+  /// A color token does not hold a valid color.
+  InvalidColor { path: String, value: String },
+  /// A custom palette color has a name that is not allowed, see
+  /// `is_valid_color_name`.
+  InvalidColorName(String),
+}
+
+impl fmt::Display for ThemeError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ThemeError::Parse(message) => {
+        write!(f, "invalid theme: {}", message)
+      }
+      ThemeError::InvalidColor { path, value } => {
+        write!(f, "invalid color \"{}\" at {}", value, path)
+      }
+      ThemeError::InvalidColorName(name) => {
+        write!(f, "invalid color name \"{}\" at palette.custom", name)
+      }
+    }
+  }
+}
+
+impl std::error::Error for ThemeError {}
+
+fn parse_error<E: fmt::Display>(error: E) -> ThemeError {
+  ThemeError::Parse(error.to_string())
+}
+
+fn merge_values(base: &mut Value, overrides: Value) {
+  match (base, overrides) {
+    (Value::Object(base), Value::Object(overrides)) => {
+      for (key, value) in overrides {
+        match base.get_mut(&key) {
+          Some(b) => { merge_values(b, value) }
+          None => { base.insert(key, value); }
+        }
+      }
+    }
+    (base, overrides) => {
+      *base = overrides;
+    }
+  }
+}
+
+/// A palette color override that sets `main` gets every other field
+/// generated from it, like `Theme::default()` does, so that shades and
+/// `contrast_text` stay in line with the new color. Fields set next to
+/// `main` still win.
+fn expand_palette_color(value: &mut Value) {
+  let color = match value.get("main").and_then(Value::as_str).map(Color::parse) {
+    Some(Ok(color)) => { color }
+    _ => { return }
+  };
+  let mut generated = match serde_json::to_value(generate_palette(color)) {
+    Ok(generated) => { generated }
+    Err(_) => { return }
+  };
+
+  merge_values(&mut generated, value.take());
+  *value = generated;
+}
+
+fn expand_palette_colors(overrides: &mut Value) {
+  let palette = match overrides.get_mut("palette") {
+    Some(Value::Object(palette)) => { palette }
+    _ => { return }
+  };
+
+  for (name, value) in palette.iter_mut() {
+    match (name.as_str(), value) {
+      ("custom", Value::Object(custom)) => {
+        custom.values_mut().for_each(expand_palette_color);
+      }
+      (_, value) => { expand_palette_color(value) }
+    }
+  }
+}
+
+fn check_color(path: &str, value: &str) -> Result<(), ThemeError> {
+  Color::parse(value)
+    .map(|_| ())
+    .map_err(|_| ThemeError::InvalidColor {
+      path: String::from(path),
+      value: String::from(value),
+    })
+}
+
+fn check_palette_color(path: &str, color: &PaletteColor) -> Result<(), ThemeError> {
+  check_color(&format!("{}.main", path), &color.main)?;
+  check_color(&format!("{}.light", path), &color.light)?;
+  check_color(&format!("{}.lightest", path), &color.lightest)?;
+  check_color(&format!("{}.dark", path), &color.dark)?;
+  check_color(&format!("{}.darkest", path), &color.darkest)?;
+  check_color(&format!("{}.contrast_text", path), &color.contrast_text)?;
+
+  for (step, shade) in &color.shades {
+    check_color(&format!("{}.shades.{}", path, step), shade)?;
+  }
+
+  Ok(())
+}
+
+impl Theme {
+  /// Builds a theme from JSON design tokens. Keys that are left out keep
+  /// their `Theme::default()` values.
+  pub fn from_json(source: &str) -> Result<Self, ThemeError> {
+    let overrides: Value = serde_json::from_str(source).map_err(parse_error)?;
+
+    Theme::default().merge(overrides)
+  }
+
+  /// Builds a theme from TOML design tokens. Keys that are left out keep
+  /// their `Theme::default()` values.
+  pub fn from_toml(source: &str) -> Result<Self, ThemeError> {
+    let overrides: toml::Value = toml::from_str(source).map_err(parse_error)?;
+
+    Theme::default().merge(serde_json::to_value(overrides).map_err(parse_error)?)
+  }
+
+  /// Returns a copy of the theme with `overrides` merged onto it. Palette
+  /// colors that only set `main` are generated from it.
+  pub fn merge(&self, mut overrides: Value) -> Result<Self, ThemeError> {
+    let mut value = serde_json::to_value(self).map_err(parse_error)?;
+
+    expand_palette_colors(&mut overrides);
+    merge_values(&mut value, overrides);
+
+    let theme: Theme = serde_json::from_value(value).map_err(parse_error)?;
+
+    theme.check_colors()?;
+    theme.check_shadows()?;
+    theme.check_spacing()?;
+
+    Ok(theme)
+  }
+
+  fn check_colors(&self) -> Result<(), ThemeError> {
+    let palette = &self.palette;
+
+    if let Some(name) = palette.custom.keys().find(|name| !is_valid_color_name(name)) {
+      return Err(ThemeError::InvalidColorName(name.clone()));
+    }
+
+    for (name, color) in palette.colors() {
+      check_palette_color(&palette.color_path(name), color)?;
+    }
+
+    check_color("palette.divider", &palette.divider)?;
+    check_color("palette.background", &palette.background)?;
+    check_color("palette.surface", &palette.surface)?;
+    check_color("palette.text_primary", &palette.text_primary)?;
+    check_color("palette.text_secondary", &palette.text_secondary)
+  }
+
+  fn check_spacing(&self) -> Result<(), ThemeError> {
+    if !self.spacing_unit.is_finite() || self.spacing_unit < 0.0 {
+      return Err(ThemeError::Parse(format!(
+        "spacing_unit: expected a non-negative number of pixels, found {}",
+        self.spacing_unit,
+      )));
+    }
+
+    Ok(())
+  }
+
+  fn check_shadows(&self) -> Result<(), ThemeError> {
+    if self.shadows.len() != ELEVATION_LEVELS {
+      return Err(ThemeError::Parse(format!(
+        "shadows: expected {} elevation levels, found {}",
+        ELEVATION_LEVELS,
+        self.shadows.len(),
+      )));
+    }
+
+    Ok(())
+  }
+}
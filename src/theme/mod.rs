@@ -0,0 +1,647 @@
+use std::collections::BTreeMap;
+use std::ops::Index;
+use serde::{Serialize, Deserialize};
+
+mod blend;
+mod breakpoints;
+mod color;
+mod contrast;
+mod elevation;
+mod loader;
+mod style_override;
+mod variables;
+pub mod transitions;
+
+pub use blend::{BlendMode, blend_color};
+pub use breakpoints::{Breakpoint, BREAKPOINTS};
+pub use color::{Color, RGB, HSL, ParseColorError};
+pub use elevation::{create_shadows, ELEVATION_LEVELS};
+pub use contrast::{ContrastIssue, contrast_text, AA_CONTRAST_RATIO, AA_LARGE_CONTRAST_RATIO};
+pub use loader::ThemeError;
+pub use style_override::StyleOverride;
+pub use variables::VARIABLE_PREFIX;
+
+/// Every field is public, a custom theme changes the ones it needs and
+/// takes the rest from the default theme:
+///
+/// ```
+/// use dao_ui::theme::{Theme, Density};
+///
+/// let theme = Theme {
+///   density: Density::Compact,
+///   unstyled: true,
+///   ..Theme::default()
+/// };
+/// ```
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Theme {
+  pub components: ThemeComponents,
+  pub palette: Palette,
+  pub breakpoints: Breakpoints,
+  pub typography: Typography,
+  /// Base unit of the spacing scale, in pixels.
+  pub spacing_unit: f64,
+  /// Default size of components that don't set one explicitly.
+  pub density: Density,
+  /// Box-shadow of every elevation level, see `ELEVATION_LEVELS`.
+  pub shadows: Vec<String>,
+  pub z_index: ZIndex,
+  pub transitions: Transitions,
+  pub reduced_motion: ReducedMotion,
+  /// Components skip their style sheets and only render BEM class names,
+  /// e.g. `dao-button dao-button--loading`, for external CSS to target.
+  #[serde(default)]
+  pub unstyled: bool,
+  /// Components use the stable class names of `ssr::extract_css`, e.g.
+  /// `dao-button`, instead of generating their style sheets at runtime.
+  #[serde(default)]
+  pub static_styles: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReducedMotion {
+  /// Follows the `prefers-reduced-motion` media query.
+  System,
+  /// Always turns transitions off.
+  Always,
+  /// Always keeps transitions on.
+  Never,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Density {
+  /// Small components, for data-dense screens such as admin dashboards.
+  Compact,
+  /// Medium components.
+  Comfortable,
+  /// Large components, where available.
+  Spacious,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Typography {
+  pub font_family: String,
+  pub h1: TypographyStyle,
+  pub h2: TypographyStyle,
+  pub h3: TypographyStyle,
+  pub h4: TypographyStyle,
+  pub h5: TypographyStyle,
+  pub h6: TypographyStyle,
+  pub subtitle: TypographyStyle,
+  pub body1: TypographyStyle,
+  pub body2: TypographyStyle,
+  pub caption: TypographyStyle,
+  pub overline: TypographyStyle,
+  pub button: TypographyStyle,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TypographyStyle {
+  pub font_size: String,
+  pub font_weight: String,
+  pub line_height: String,
+  pub letter_spacing: String,
+}
+
+impl TypographyStyle {
+  pub fn new(font_size: &str, font_weight: &str, line_height: &str, letter_spacing: &str) -> Self {
+    TypographyStyle {
+      font_size: String::from(font_size),
+      font_weight: String::from(font_weight),
+      line_height: String::from(line_height),
+      letter_spacing: String::from(letter_spacing),
+    }
+  }
+}
+
+impl Typography {
+  /// Returns every variant of the type scale with its name.
+  pub fn variants(&self) -> Vec<(&str, &TypographyStyle)> {
+    vec![
+      ("h1", &self.h1),
+      ("h2", &self.h2),
+      ("h3", &self.h3),
+      ("h4", &self.h4),
+      ("h5", &self.h5),
+      ("h6", &self.h6),
+      ("subtitle", &self.subtitle),
+      ("body1", &self.body1),
+      ("body2", &self.body2),
+      ("caption", &self.caption),
+      ("overline", &self.overline),
+      ("button", &self.button),
+    ]
+  }
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Palette {
+  pub primary: PaletteColor,
+  pub secondary: PaletteColor,
+  pub error: PaletteColor,
+  pub success: PaletteColor,
+  pub warning: PaletteColor,
+  pub info: PaletteColor,
+  /// Extra named colors, see `Palette::add_color`.
+  #[serde(default)]
+  pub custom: BTreeMap<String, PaletteColor>,
+  pub divider: String,
+  pub background: String,
+  pub surface: String,
+  pub text_primary: String,
+  pub text_secondary: String,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaletteColor {
+  pub main: String,
+  pub light: String,
+  pub lightest: String,
+  pub dark: String,
+  pub darkest: String,
+  /// Text color readable on top of `main`.
+  pub contrast_text: String,
+  /// Tonal scale keyed by step, see `SHADE_STEPS`.
+  pub shades: BTreeMap<u16, String>,
+}
+
+/// Names taken by the built-in palette variables, e.g. `--dao-palette-surface`.
+const RESERVED_COLOR_NAMES: [&str; 10] = [
+  "primary", "secondary", "error", "success", "warning", "info",
+  "divider", "background", "surface", "text",
+];
+
+/// Whether `name` can be used for a custom palette color. Names end up in
+/// custom property names and inline styles, so they are limited to
+/// lowercase letters, digits and `-`, and can't shadow the variables of a
+/// built-in color: `primary` and `primary-contrast` are both rejected.
+pub fn is_valid_color_name(name: &str) -> bool {
+  let charset = !name.is_empty()
+    && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+  let reserved = RESERVED_COLOR_NAMES.iter()
+    .any(|r| name == *r || name.strip_prefix(r).is_some_and(|rest| rest.starts_with('-')));
+
+  charset && !reserved
+}
+
+impl Palette {
+  /// Generates a palette color from `color` and registers it under `name`,
+  /// e.g. `brand-accent`. Components refer to it as `ButtonColor::Custom(name)`.
+  /// Fails when the name is not valid, see `is_valid_color_name`.
+  pub fn add_color(&mut self, name: &str, color: Color) -> Result<(), ThemeError> {
+    if !is_valid_color_name(name) {
+      return Err(ThemeError::InvalidColorName(String::from(name)));
+    }
+
+    self.custom.insert(String::from(name), generate_palette(color));
+
+    Ok(())
+  }
+
+  /// Returns a built-in or custom palette color by name.
+  pub fn color(&self, name: &str) -> Option<&PaletteColor> {
+    match name {
+      "primary" => { Some(&self.primary) }
+      "secondary" => { Some(&self.secondary) }
+      "error" => { Some(&self.error) }
+      "success" => { Some(&self.success) }
+      "warning" => { Some(&self.warning) }
+      "info" => { Some(&self.info) }
+      _ => { self.custom.get(name) }
+    }
+  }
+
+  /// Returns every palette color with its name, built-in ones first.
+  pub fn colors(&self) -> Vec<(&str, &PaletteColor)> {
+    let mut colors = vec![
+      ("primary", &self.primary),
+      ("secondary", &self.secondary),
+      ("error", &self.error),
+      ("success", &self.success),
+      ("warning", &self.warning),
+      ("info", &self.info),
+    ];
+
+    colors.extend(self.custom.iter().map(|(name, color)| (name.as_str(), color)));
+    colors
+  }
+
+  /// Path of a palette color inside the theme, as used in error reports.
+  pub(crate) fn color_path(&self, name: &str) -> String {
+    if self.custom.contains_key(name) {
+      format!("palette.custom.{}", name)
+    } else {
+      format!("palette.{}", name)
+    }
+  }
+}
+
+/// Steps of the tonal scale, from the lightest to the darkest shade.
+/// `500` is the main color.
+pub const SHADE_STEPS: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+impl PaletteColor {
+  pub fn shade(&self, step: u16) -> Option<&str> {
+    self.shades.get(&step).map(String::as_str)
+  }
+}
+
+impl Index<u16> for PaletteColor {
+  type Output = String;
+
+  fn index(&self, step: u16) -> &String {
+    self.shades.get(&step)
+      .unwrap_or_else(|| panic!("palette color has no {} shade", step))
+  }
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Breakpoints {
+  pub xs: i32,
+  pub sm: i32,
+  pub md: i32,
+  pub lg: i32,
+  pub xl: i32,
+}
+
+/// Stacking order of overlapping components.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ZIndex {
+  pub app_bar: i32,
+  pub drawer: i32,
+  pub modal: i32,
+  pub snackbar: i32,
+  pub tooltip: i32,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Transitions {
+  pub duration: TransitionDurations,
+  pub easing: TransitionEasings,
+}
+
+/// Durations in milliseconds.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransitionDurations {
+  pub shortest: u32,
+  pub shorter: u32,
+  pub short: u32,
+  pub standard: u32,
+  pub complex: u32,
+  pub entering: u32,
+  pub leaving: u32,
+  /// One turn of loading spinners. Spinners keep turning with reduced
+  /// motion, they are the only sign that something is happening.
+  pub spinner: u32,
+}
+
+/// Easing curves as CSS timing functions.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransitionEasings {
+  pub ease_in_out: String,
+  pub ease_out: String,
+  pub ease_in: String,
+  pub sharp: String,
+}
+
+impl TransitionDurations {
+  /// Returns every duration that reduced motion turns off, with its name.
+  pub fn values(&self) -> Vec<(&str, u32)> {
+    vec![
+      ("shortest", self.shortest),
+      ("shorter", self.shorter),
+      ("short", self.short),
+      ("standard", self.standard),
+      ("complex", self.complex),
+      ("entering", self.entering),
+      ("leaving", self.leaving),
+    ]
+  }
+}
+
+impl TransitionEasings {
+  /// Returns every easing curve with its name.
+  pub fn values(&self) -> Vec<(&str, &String)> {
+    vec![
+      ("ease-in-out", &self.ease_in_out),
+      ("ease-out", &self.ease_out),
+      ("ease-in", &self.ease_in),
+      ("sharp", &self.sharp),
+    ]
+  }
+}
+
+pub const DEFAULT_SPACING_UNIT: f64 = 8.0;
+
+/// Returns `n` spacing units as a CSS expression that follows the
+/// `--dao-spacing-unit` of the surrounding theme.
+pub fn spacing_var(n: f64) -> String {
+  format!("calc(var(--dao-spacing-unit) * {})", n)
+}
+
+/// Opacity used for hover backgrounds of outlined and inline components.
+pub const FADE_ALPHA: f64 = 0.05;
+
+/// Returns `color` with `FADE_ALPHA` opacity. Colors that can't be parsed are
+/// returned unchanged.
+pub fn fade(color: String) -> String {
+  match Color::parse(&color) {
+    Ok(c) => { c.alpha(FADE_ALPHA).to_css_string() }
+    Err(_) => { color }
+  }
+}
+
+pub fn generate_palette(color: Color) -> PaletteColor {
+  let l1_additive = Color::from_rgb(70, 70, 70);
+  let l2_additive = Color::from_rgb(137, 137, 137);
+  let l3_additive = Color::from_rgb(184, 184, 184);
+  let l4_additive = Color::from_rgb(218, 218, 218);
+  let l5_additive = Color::from_rgb(240, 240, 240);
+  let d1_additive = Color::from_rgb(225, 225, 225);
+  let d2_additive = Color::from_rgb(194, 194, 194);
+  let d3_additive = Color::from_rgb(155, 155, 155);
+  let d4_additive = Color::from_rgb(114, 114, 114);
+  let d5_additive = Color::from_rgb(76, 76, 76);
+
+  let lighten = |additive| blend_color(BlendMode::Screen, color, additive).to_hex();
+  let darken = |additive| blend_color(BlendMode::Multiply, color, additive).to_hex();
+
+  let shades: BTreeMap<u16, String> = SHADE_STEPS.iter().cloned().zip(vec![
+    lighten(l5_additive),
+    lighten(l4_additive),
+    lighten(l3_additive),
+    lighten(l2_additive),
+    lighten(l1_additive),
+    color.to_hex(),
+    darken(d1_additive),
+    darken(d2_additive),
+    darken(d3_additive),
+    darken(d4_additive),
+    darken(d5_additive),
+  ]).collect();
+
+  PaletteColor {
+    main: shades[&500].clone(),
+    light: shades[&400].clone(),
+    lightest: shades[&300].clone(),
+    dark: shades[&600].clone(),
+    darkest: shades[&700].clone(),
+    contrast_text: contrast_text(&color).to_hex(),
+    shades,
+  }
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThemeComponents {
+  pub button: ComponentButton,
+  #[serde(default)]
+  pub button_icon: ComponentStyle,
+  #[serde(default)]
+  pub button_group: ComponentStyle,
+  #[serde(default)]
+  pub icon_button: ComponentStyle,
+  #[serde(default)]
+  pub menu_item: ComponentStyle,
+  #[serde(default)]
+  pub table: ComponentStyle,
+  #[serde(default)]
+  pub table_head: ComponentStyle,
+  #[serde(default)]
+  pub table_body: ComponentStyle,
+  #[serde(default)]
+  pub table_row: ComponentStyle,
+  #[serde(default)]
+  pub table_cell: ComponentStyle,
+  #[serde(default)]
+  pub typography: ComponentStyle,
+}
+
+/// Settings of components that have no design tokens of their own.
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ComponentStyle {
+  /// CSS added to every instance of the component, in the format of
+  /// `StyleOverride`.
+  #[serde(default)]
+  pub style_overrides: String,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ComponentButton {
+  pub border_radius: String,
+  pub small_size_padding: String,
+  pub large_size_padding: String,
+  pub small_height: String,
+  pub large_height: String,
+  /// CSS added to every button, in the format of `StyleOverride`.
+  #[serde(default)]
+  pub style_overrides: String,
+}
+
+impl Default for Theme {
+  fn default() -> Self {
+    Theme {
+      components: Theme::get_default_components(),
+      palette: Theme::get_default_palette(),
+      breakpoints: Theme::get_default_breakpoints(),
+      typography: Theme::get_default_typography(),
+      spacing_unit: DEFAULT_SPACING_UNIT,
+      density: Density::Comfortable,
+      shadows: Theme::get_default_shadows(),
+      z_index: Theme::get_default_z_index(),
+      transitions: Theme::get_default_transitions(),
+      reduced_motion: ReducedMotion::System,
+      unstyled: false,
+      static_styles: false,
+    }
+  }
+}
+
+impl Theme {
+  /// Returns the box-shadow of an elevation level. Levels above the scale
+  /// get the highest shadow.
+  pub fn shadow(&self, elevation: usize) -> &str {
+    self.shadows.get(elevation)
+      .or_else(|| self.shadows.last())
+      .map(String::as_str)
+      .unwrap_or("none")
+  }
+
+  /// Returns `n` spacing units in pixels, e.g. `spacing(2)` is `16px` with
+  /// the default unit.
+  pub fn spacing<N: Into<f64>>(&self, n: N) -> String {
+    format!("{}px", n.into() * self.spacing_unit)
+  }
+
+  /// Returns a `padding`/`margin` shorthand of four spacing values.
+  pub fn spacing4<T, R, B, L>(&self, top: T, right: R, bottom: B, left: L) -> String
+  where
+    T: Into<f64>,
+    R: Into<f64>,
+    B: Into<f64>,
+    L: Into<f64>,
+  {
+    format!(
+      "{} {} {} {}",
+      self.spacing(top),
+      self.spacing(right),
+      self.spacing(bottom),
+      self.spacing(left),
+    )
+  }
+
+  pub fn dark() -> Self {
+    Theme {
+      palette: Theme::get_dark_palette(),
+      shadows: Theme::get_dark_shadows(),
+      ..Theme::default()
+    }
+  }
+
+  pub fn get_default_palette() -> Palette {
+    Palette {
+      primary: generate_palette(Color::from_rgb(13, 183, 182)),
+      secondary: generate_palette(Color::from_rgb(234, 40, 69)),
+      error: generate_palette(Color::from_rgb(244, 67, 54)),
+      success: generate_palette(Color::from_rgb(76, 175, 80)),
+      warning: generate_palette(Color::from_rgb(255, 152, 0)),
+      info: generate_palette(Color::from_rgb(33, 150, 243)),
+      custom: BTreeMap::new(),
+      divider: String::from("#DDE3ED"),
+      background: String::from("#f5f7fa"),
+      surface: String::from("#ffffff"),
+      text_primary: String::from("#212121"),
+      // Not the former #757575, which is 4.3:1 on `background` and fails
+      // the AA check of `Theme::validate`.
+      text_secondary: String::from("#666666"),
+    }
+  }
+
+  pub fn get_dark_palette() -> Palette {
+    Palette {
+      primary: generate_palette(Color::from_rgb(38, 198, 197)),
+      secondary: generate_palette(Color::from_rgb(240, 98, 119)),
+      error: generate_palette(Color::from_rgb(239, 83, 80)),
+      success: generate_palette(Color::from_rgb(102, 187, 106)),
+      warning: generate_palette(Color::from_rgb(255, 167, 38)),
+      info: generate_palette(Color::from_rgb(66, 165, 245)),
+      custom: BTreeMap::new(),
+      divider: String::from("#33383f"),
+      background: String::from("#121212"),
+      surface: String::from("#1e1e1e"),
+      text_primary: String::from("#ffffff"),
+      text_secondary: String::from("#b0b3b8"),
+    }
+  }
+
+  pub fn get_default_components() -> ThemeComponents {
+    ThemeComponents {
+      button: Theme::get_default_component_button(),
+      button_icon: ComponentStyle::default(),
+      button_group: ComponentStyle::default(),
+      icon_button: ComponentStyle::default(),
+      menu_item: ComponentStyle::default(),
+      table: ComponentStyle::default(),
+      table_head: ComponentStyle::default(),
+      table_body: ComponentStyle::default(),
+      table_row: ComponentStyle::default(),
+      table_cell: ComponentStyle::default(),
+      typography: ComponentStyle::default(),
+    }
+  }
+
+  pub fn get_default_component_button() -> ComponentButton {
+    ComponentButton {
+      border_radius: String::from("4px"),
+      small_size_padding: format!("{} {}", spacing_var(1.25), spacing_var(2.0)),
+      large_size_padding: format!("{} {}", spacing_var(1.5), spacing_var(4.0)),
+      small_height: String::from("36px"),
+      large_height: String::from("43px"),
+      style_overrides: String::new(),
+    }
+  }
+
+  pub fn get_default_breakpoints() -> Breakpoints {
+    Breakpoints {
+      xs: 320,
+      sm: 480,
+      md: 768,
+      lg: 1000,
+      xl: 1280,
+    }
+  }
+
+  pub fn get_default_shadows() -> Vec<String> {
+    create_shadows(0.2, 0.14, 0.12)
+  }
+
+  /// Dark surfaces need stronger shadows for the same perceived elevation.
+  pub fn get_dark_shadows() -> Vec<String> {
+    create_shadows(0.4, 0.28, 0.24)
+  }
+
+  pub fn get_default_z_index() -> ZIndex {
+    ZIndex {
+      app_bar: 1100,
+      drawer: 1200,
+      modal: 1300,
+      snackbar: 1400,
+      tooltip: 1500,
+    }
+  }
+
+  pub fn get_default_transitions() -> Transitions {
+    Transitions {
+      duration: TransitionDurations {
+        shortest: 150,
+        shorter: 200,
+        short: 250,
+        standard: 300,
+        complex: 375,
+        entering: 225,
+        leaving: 195,
+        spinner: 2000,
+      },
+      easing: TransitionEasings {
+        ease_in_out: String::from("cubic-bezier(0.4, 0, 0.2, 1)"),
+        ease_out: String::from("cubic-bezier(0.0, 0, 0.2, 1)"),
+        ease_in: String::from("cubic-bezier(0.4, 0, 1, 1)"),
+        sharp: String::from("cubic-bezier(0.4, 0, 0.6, 1)"),
+      },
+    }
+  }
+
+  pub fn get_default_typography() -> Typography {
+    Typography {
+      font_family: String::from("-apple-system, BlinkMacSystemFont, 'Segoe UI', 'Roboto', 'Oxygen',
+      'Ubuntu', 'Cantarell', 'Fira Sans', 'Droid Sans', 'Helvetica Neue',
+      sans-serif"),
+      h1: TypographyStyle::new("96px", "300", "1.167", "-1.5px"),
+      h2: TypographyStyle::new("60px", "300", "1.2", "-0.5px"),
+      h3: TypographyStyle::new("48px", "400", "1.167", "0"),
+      h4: TypographyStyle::new("34px", "400", "1.235", "0.25px"),
+      h5: TypographyStyle::new("24px", "400", "1.334", "0"),
+      h6: TypographyStyle::new("20px", "500", "1.6", "0.15px"),
+      subtitle: TypographyStyle::new("16px", "400", "1.75", "0.15px"),
+      body1: TypographyStyle::new("16px", "400", "1.5", "0.15px"),
+      body2: TypographyStyle::new("14px", "400", "1.43", "0.15px"),
+      caption: TypographyStyle::new("12px", "400", "1.66", "0.4px"),
+      overline: TypographyStyle::new("12px", "400", "2.66", "1px"),
+      button: TypographyStyle::new("14px", "500", "16px", "normal"),
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,69 @@
+use std::fmt;
+use std::rc::Rc;
+use super::Theme;
+
+/// Extra CSS for a single component instance, passed through its `sx` prop.
+///
+/// The CSS is either plain declarations for the root element, e.g.
+/// `"margin-top: 8px;"`, or rules that use `&` for it, e.g.
+/// `"&:hover { opacity: 0.8; }"`. It wins over the component's own rules
+/// and over the theme's `style_overrides`.
+#[derive(Clone)]
+pub enum StyleOverride {
+  Css(String),
+  Themed(Rc<dyn Fn(&Theme) -> String>),
+}
+
+impl StyleOverride {
+  pub fn css<S: Into<String>>(css: S) -> Self {
+    StyleOverride::Css(css.into())
+  }
+
+  /// CSS built from the theme of the component, called again when the
+  /// theme or the props change. Components compare overrides by this CSS,
+  /// so the closure can be created in `view`.
+  pub fn themed<F>(create: F) -> Self
+  where
+    F: Fn(&Theme) -> String + 'static,
+  {
+    StyleOverride::Themed(Rc::new(create))
+  }
+
+  pub fn to_css(&self, theme: &Theme) -> String {
+    match self {
+      StyleOverride::Css(css) => { css.clone() }
+      StyleOverride::Themed(create) => { create(theme) }
+    }
+  }
+}
+
+impl From<&str> for StyleOverride {
+  fn from(css: &str) -> Self {
+    StyleOverride::css(css)
+  }
+}
+
+impl From<String> for StyleOverride {
+  fn from(css: String) -> Self {
+    StyleOverride::Css(css)
+  }
+}
+
+impl PartialEq for StyleOverride {
+  fn eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      (StyleOverride::Css(a), StyleOverride::Css(b)) => { a == b }
+      (StyleOverride::Themed(a), StyleOverride::Themed(b)) => { Rc::ptr_eq(a, b) }
+      _ => { false }
+    }
+  }
+}
+
+impl fmt::Debug for StyleOverride {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      StyleOverride::Css(css) => { f.debug_tuple("Css").field(css).finish() }
+      StyleOverride::Themed(_) => { f.write_str("Themed(..)") }
+    }
+  }
+}
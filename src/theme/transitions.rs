@@ -0,0 +1,66 @@
+use super::VARIABLE_PREFIX;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Duration {
+  Shortest,
+  Shorter,
+  Short,
+  Standard,
+  Complex,
+  Entering,
+  Leaving,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Easing {
+  EaseInOut,
+  EaseOut,
+  EaseIn,
+  Sharp,
+}
+
+impl Duration {
+  pub(crate) fn name(self) -> &'static str {
+    match self {
+      Duration::Shortest => { "shortest" }
+      Duration::Shorter => { "shorter" }
+      Duration::Short => { "short" }
+      Duration::Standard => { "standard" }
+      Duration::Complex => { "complex" }
+      Duration::Entering => { "entering" }
+      Duration::Leaving => { "leaving" }
+    }
+  }
+
+  /// The duration as a CSS expression that follows the surrounding theme,
+  /// including its reduced motion setting.
+  pub fn var(self) -> String {
+    format!("var({}-transition-duration-{})", VARIABLE_PREFIX, self.name())
+  }
+}
+
+impl Easing {
+  pub(crate) fn name(self) -> &'static str {
+    match self {
+      Easing::EaseInOut => { "ease-in-out" }
+      Easing::EaseOut => { "ease-out" }
+      Easing::EaseIn => { "ease-in" }
+      Easing::Sharp => { "sharp" }
+    }
+  }
+
+  /// The easing curve as a CSS expression that follows the surrounding theme.
+  pub fn var(self) -> String {
+    format!("var({}-transition-easing-{})", VARIABLE_PREFIX, self.name())
+  }
+}
+
+/// Returns a `transition` value that animates every property of `props`
+/// with the same timing, e.g.
+/// `create(&["color", "border-color"], Duration::Short, Easing::EaseInOut)`.
+pub fn create(props: &[&str], duration: Duration, easing: Easing) -> String {
+  props.iter()
+    .map(|prop| format!("{} {} {}", prop, duration.var(), easing.var()))
+    .collect::<Vec<String>>()
+    .join(", ")
+}
@@ -0,0 +1,150 @@
+use super::{Theme, Color, PaletteColor, ReducedMotion, fade, is_valid_color_name};
+
+/// Prefix of every custom property written for a theme.
+pub const VARIABLE_PREFIX: &str = "--dao";
+
+fn push_palette_color(
+  variables: &mut Vec<(String, String)>,
+  name: &str,
+  color: &PaletteColor,
+  text_backgrounds: &[Color],
+) {
+  let prefix = format!("{}-palette-{}", VARIABLE_PREFIX, name);
+
+  variables.push((format!("{}-main", prefix), color.main.clone()));
+  variables.push((format!("{}-main-fade", prefix), fade(color.main.clone())));
+  variables.push((format!("{}-light", prefix), color.light.clone()));
+  variables.push((format!("{}-lightest", prefix), color.lightest.clone()));
+  variables.push((format!("{}-dark", prefix), color.dark.clone()));
+  variables.push((format!("{}-darkest", prefix), color.darkest.clone()));
+  variables.push((format!("{}-contrast-text", prefix), color.contrast_text.clone()));
+  variables.push((format!("{}-text", prefix), String::from(color.text_on(text_backgrounds))));
+
+  for (step, shade) in &color.shades {
+    variables.push((format!("{}-{}", prefix, step), shade.clone()));
+  }
+}
+
+impl Theme {
+  /// Returns every theme token as a `(custom property, value)` pair,
+  /// e.g. `("--dao-palette-primary-main", "#0db7b6")`. Custom colors with
+  /// an invalid name, see `is_valid_color_name`, are left out.
+  pub fn css_variables(&self) -> Vec<(String, String)> {
+    let mut variables = vec![];
+    let palette = &self.palette;
+    let button = &self.components.button;
+    let z_index = &self.z_index;
+    let text_backgrounds = palette.text_backgrounds();
+
+    // `palette.custom` is public, names that didn't go through
+    // `Palette::add_color` or the loader are checked before being written.
+    for (name, color) in palette.colors() {
+      if !palette.custom.contains_key(name) || is_valid_color_name(name) {
+        push_palette_color(&mut variables, name, color, &text_backgrounds);
+      }
+    }
+
+    for (name, value) in [
+      ("palette-divider", &palette.divider),
+      ("palette-background", &palette.background),
+      ("palette-surface", &palette.surface),
+      ("palette-text-primary", &palette.text_primary),
+      ("palette-text-secondary", &palette.text_secondary),
+      ("typography-font-family", &self.typography.font_family),
+      ("button-border-radius", &button.border_radius),
+      ("button-small-size-padding", &button.small_size_padding),
+      ("button-large-size-padding", &button.large_size_padding),
+      ("button-small-height", &button.small_height),
+      ("button-large-height", &button.large_height),
+    ] {
+      variables.push((format!("{}-{}", VARIABLE_PREFIX, name), value.clone()));
+    }
+
+    for (name, style) in self.typography.variants() {
+      let prefix = format!("{}-typography-{}", VARIABLE_PREFIX, name);
+
+      variables.push((format!("{}-font-size", prefix), style.font_size.clone()));
+      variables.push((format!("{}-font-weight", prefix), style.font_weight.clone()));
+      variables.push((format!("{}-line-height", prefix), style.line_height.clone()));
+      variables.push((format!("{}-letter-spacing", prefix), style.letter_spacing.clone()));
+    }
+
+    let reduce_motion = self.reduced_motion == ReducedMotion::Always;
+
+    for (name, duration) in self.transitions.duration.values() {
+      let value = if reduce_motion { 0 } else { duration };
+
+      variables.push((format!("{}-transition-duration-{}", VARIABLE_PREFIX, name), format!("{}ms", value)));
+    }
+
+    variables.push((
+      format!("{}-transition-duration-spinner", VARIABLE_PREFIX),
+      format!("{}ms", self.transitions.duration.spinner),
+    ));
+
+    for (name, easing) in self.transitions.easing.values() {
+      variables.push((format!("{}-transition-easing-{}", VARIABLE_PREFIX, name), easing.clone()));
+    }
+
+    variables.push((format!("{}-spacing-unit", VARIABLE_PREFIX), format!("{}px", self.spacing_unit)));
+
+    for (level, shadow) in self.shadows.iter().enumerate() {
+      variables.push((format!("{}-shadow-{}", VARIABLE_PREFIX, level), shadow.clone()));
+    }
+
+    for (name, value) in [
+      ("app-bar", z_index.app_bar),
+      ("drawer", z_index.drawer),
+      ("modal", z_index.modal),
+      ("snackbar", z_index.snackbar),
+      ("tooltip", z_index.tooltip),
+    ] {
+      variables.push((format!("{}-z-index-{}", VARIABLE_PREFIX, name), value.to_string()));
+    }
+
+    for (breakpoint, value) in self.breakpoints.values() {
+      variables.push((format!("{}-breakpoint-{}", VARIABLE_PREFIX, breakpoint.name()), format!("{}px", value)));
+    }
+
+    variables
+  }
+
+  /// Returns the theme as custom property declarations, ready to be placed
+  /// inside any rule block.
+  pub fn to_css_declarations(&self) -> String {
+    self.css_variables()
+      .into_iter()
+      .map(|(name, value)| format!("  {}: {};\n", name, value))
+      .collect()
+  }
+
+  /// Returns a rule that turns transitions off under `selector` when the
+  /// user prefers reduced motion. Empty unless the theme follows the system.
+  /// The declarations are `!important` to also win over variables set
+  /// inline, like those of a `ThemeProvider` without a generated sheet.
+  pub fn to_reduced_motion_css(&self, selector: &str) -> String {
+    if self.reduced_motion != ReducedMotion::System {
+      return String::new();
+    }
+
+    let declarations: String = self.transitions.duration.values()
+      .into_iter()
+      .map(|(name, _)| format!("    {}-transition-duration-{}: 0ms !important;\n", VARIABLE_PREFIX, name))
+      .collect();
+
+    format!(
+      "@media (prefers-reduced-motion: reduce) {{\n  {} {{\n{}  }}\n}}\n",
+      selector,
+      declarations,
+    )
+  }
+
+  /// Returns a `:root` rule that applies the theme to the whole document.
+  pub fn to_root_css(&self) -> String {
+    format!(
+      ":root {{\n{}}}\n{}",
+      self.to_css_declarations(),
+      self.to_reduced_motion_css(":root"),
+    )
+  }
+}
@@ -0,0 +1,84 @@
+use dao_ui::theme::{blend_color, BlendMode, Color, RGB};
+
+// Reference values follow the W3C Compositing and Blending Level 1 formulas,
+// computed independently and truncated to integer channels like `blend_color` does.
+
+fn blend(mode: BlendMode, backdrop: (i32, i32, i32), source: (i32, i32, i32)) -> RGB {
+  blend_color(
+    mode,
+    Color::from_rgb(backdrop.0, backdrop.1, backdrop.2),
+    Color::from_rgb(source.0, source.1, source.2),
+  ).rgb
+}
+
+const BACKDROP: (i32, i32, i32) = (13, 183, 182);
+const SOURCE: (i32, i32, i32) = (200, 90, 40);
+
+#[test]
+fn multiply() {
+  assert_eq!(blend(BlendMode::Multiply, BACKDROP, SOURCE), RGB(10, 64, 28));
+}
+
+#[test]
+fn screen() {
+  assert_eq!(blend(BlendMode::Screen, BACKDROP, SOURCE), RGB(202, 208, 193));
+}
+
+#[test]
+fn overlay() {
+  assert_eq!(blend(BlendMode::Overlay, BACKDROP, SOURCE), RGB(20, 161, 131));
+}
+
+#[test]
+fn darken() {
+  assert_eq!(blend(BlendMode::Darken, BACKDROP, SOURCE), RGB(13, 90, 40));
+}
+
+#[test]
+fn lighten() {
+  assert_eq!(blend(BlendMode::Lighten, BACKDROP, SOURCE), RGB(200, 183, 182));
+}
+
+#[test]
+fn color_dodge() {
+  assert_eq!(blend(BlendMode::ColorDodge, BACKDROP, SOURCE), RGB(60, 255, 215));
+  assert_eq!(blend(BlendMode::ColorDodge, (0, 128, 255), (255, 255, 255)), RGB(0, 255, 255));
+}
+
+#[test]
+fn color_burn() {
+  assert_eq!(blend(BlendMode::ColorBurn, BACKDROP, SOURCE), RGB(0, 51, 0));
+  assert_eq!(blend(BlendMode::ColorBurn, (255, 128, 0), (0, 0, 0)), RGB(255, 0, 0));
+}
+
+#[test]
+fn hard_light() {
+  assert_eq!(blend(BlendMode::HardLight, BACKDROP, SOURCE), RGB(150, 129, 57));
+}
+
+#[test]
+fn soft_light() {
+  assert_eq!(blend(BlendMode::SoftLight, BACKDROP, SOURCE), RGB(30, 167, 146));
+}
+
+#[test]
+fn difference() {
+  assert_eq!(blend(BlendMode::Difference, BACKDROP, SOURCE), RGB(187, 93, 142));
+}
+
+#[test]
+fn exclusion() {
+  assert_eq!(blend(BlendMode::Exclusion, BACKDROP, SOURCE), RGB(192, 143, 164));
+}
+
+#[test]
+fn translucent_source_is_composited_over_backdrop() {
+  let result = blend_color(
+    BlendMode::Multiply,
+    Color::from_rgb(255, 255, 255),
+    Color::from_rgb(0, 0, 0).alpha(0.5),
+  );
+
+  assert_eq!(result.rgb, RGB(127, 127, 127));
+  assert_eq!(result.alpha, 1.0);
+}
@@ -0,0 +1,32 @@
+use dao_ui::theme::{Breakpoint, Theme};
+
+#[test]
+fn queries() {
+  let breakpoints = Theme::default().breakpoints;
+
+  assert_eq!(breakpoints.up(Breakpoint::Md), "@media (min-width: 768px)");
+  assert_eq!(breakpoints.down(Breakpoint::Md), "@media (max-width: 767.98px)");
+  assert_eq!(
+    breakpoints.between(Breakpoint::Sm, Breakpoint::Lg),
+    "@media (min-width: 480px) and (max-width: 999.98px)",
+  );
+}
+
+#[test]
+fn nothing_is_below_xs() {
+  let breakpoints = Theme::default().breakpoints;
+
+  assert_eq!(breakpoints.down_query(Breakpoint::Xs), "not all");
+  assert_eq!(breakpoints.down(Breakpoint::Xs), "@media not all");
+  assert_eq!(breakpoints.between(Breakpoint::Xs, Breakpoint::Xs), "@media not all");
+}
+
+#[test]
+fn current() {
+  let breakpoints = Theme::default().breakpoints;
+
+  assert_eq!(breakpoints.current(0.0), Breakpoint::Xs);
+  assert_eq!(breakpoints.current(767.5), Breakpoint::Sm);
+  assert_eq!(breakpoints.current(768.0), Breakpoint::Md);
+  assert_eq!(breakpoints.current(2000.0), Breakpoint::Xl);
+}
@@ -0,0 +1,144 @@
+use dao_ui::theme::{Color, HSL, RGB};
+
+fn parse(value: &str) -> Color {
+  Color::parse(value).unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn rgba(r: i32, g: i32, b: i32, alpha: f64) -> Color {
+  Color::new(RGB(r, g, b), alpha)
+}
+
+fn assert_close(actual: f64, expected: f64) {
+  assert!((actual - expected).abs() < 0.01, "{} != {}", actual, expected);
+}
+
+#[test]
+fn hex() {
+  assert_eq!(parse("#0db7b6"), Color::from_rgb(13, 183, 182));
+  assert_eq!(parse("#0DB7B6"), Color::from_rgb(13, 183, 182));
+  assert_eq!(parse("#fa0"), Color::from_rgb(255, 170, 0));
+  assert_eq!(parse("#fa08"), rgba(255, 170, 0, 136.0 / 255.0));
+  assert_eq!(parse("#0db7b680"), rgba(13, 183, 182, 128.0 / 255.0));
+}
+
+#[test]
+fn rgb_functions() {
+  assert_eq!(parse("rgb(13, 183, 182)"), Color::from_rgb(13, 183, 182));
+  assert_eq!(parse("rgb(13 183 182)"), Color::from_rgb(13, 183, 182));
+  assert_eq!(parse("rgb(100%, 0%, 50%)"), Color::from_rgb(255, 0, 128));
+  assert_eq!(parse("rgba(13, 183, 182, 0.5)"), rgba(13, 183, 182, 0.5));
+  assert_eq!(parse("rgba(13 183 182 / 50%)"), rgba(13, 183, 182, 0.5));
+  assert_eq!(parse("rgb(13 183 182 / 0.25)"), rgba(13, 183, 182, 0.25));
+}
+
+#[test]
+fn hsl_functions() {
+  assert_eq!(parse("hsl(0, 100%, 50%)"), Color::from_rgb(255, 0, 0));
+  assert_eq!(parse("hsl(120 100% 25%)"), Color::from_rgb(0, 128, 0));
+  assert_eq!(parse("hsl(0.5turn 100% 50%)"), Color::from_rgb(0, 255, 255));
+  assert_eq!(parse("hsl(-120deg, 100%, 50%)"), Color::from_rgb(0, 0, 255));
+  assert_eq!(parse("hsla(240, 100%, 50%, 0.5)"), rgba(0, 0, 255, 0.5));
+  assert_eq!(parse("hsla(240 100% 50% / 50%)"), rgba(0, 0, 255, 0.5));
+}
+
+#[test]
+fn named_colors() {
+  assert_eq!(parse("rebeccapurple"), Color::from_rgb(102, 51, 153));
+  assert_eq!(parse("  White "), Color::from_rgb(255, 255, 255));
+  assert_eq!(parse("transparent"), rgba(0, 0, 0, 0.0));
+}
+
+#[test]
+fn out_of_range_values_are_errors() {
+  for value in [
+    "rgb(256, 0, 0)",
+    "rgb(-1, 0, 0)",
+    "rgb(101%, 0%, 0%)",
+    "rgba(0, 0, 0, 1.5)",
+    "rgba(0 0 0 / 120%)",
+    "hsl(0, 101%, 50%)",
+    "hsl(0 50% -10%)",
+  ] {
+    assert!(Color::parse(value).is_err(), "{} parsed", value);
+  }
+}
+
+#[test]
+fn malformed_values_are_errors() {
+  for value in [
+    "",
+    "#",
+    "#12",
+    "#12345",
+    "#1234567",
+    "#ggg",
+    "0db7b6",
+    "rgb(1, 2)",
+    "rgb(1, 2, 3, 4, 5)",
+    "rgb(1 2 3 4)",
+    "rgb(1, 2, 3",
+    "rgb(a, b, c)",
+    "rgb(NaN, 0, 0)",
+    "hsl(red, 50%, 50%)",
+    "cmyk(0, 0, 0, 0)",
+    "notacolor",
+  ] {
+    let error = Color::parse(value).unwrap_err();
+
+    assert_eq!(error.to_string(), format!("invalid color \"{}\"", value));
+  }
+}
+
+#[test]
+fn conversions() {
+  let color = Color::from_rgb(255, 0, 0);
+
+  assert_eq!(color.hsl(), HSL(0.0, 100.0, 50.0));
+  assert_eq!(color.to_hex(), "#ff0000");
+  assert_eq!(color.alpha(0.5).to_css_string(), "rgba(255, 0, 0, 0.5)");
+  assert_eq!(Color::from_hsl(HSL(0.0, 100.0, 50.0), 1.0), color);
+}
+
+#[test]
+fn formatting_clamps_fields_set_directly() {
+  let color = Color { rgb: RGB(300, -20, 128), alpha: 1.0 };
+
+  assert_eq!(color.to_hex(), "#ff0080");
+  assert_eq!(color.to_css_string(), "#ff0080");
+  assert_eq!(Color { alpha: 0.5, ..color }.to_css_string(), "rgba(255, 0, 128, 0.5)");
+  assert_eq!(Color { alpha: -1.0, ..color }.to_css_string(), "rgba(255, 0, 128, 0)");
+}
+
+#[test]
+fn lighten_and_darken() {
+  let red = Color::from_rgb(255, 0, 0);
+
+  assert_eq!(red.lighten(0.2), Color::from_rgb(255, 102, 102));
+  assert_eq!(red.darken(0.2), Color::from_rgb(153, 0, 0));
+  assert_eq!(red.lighten(1.0), Color::from_rgb(255, 255, 255));
+  assert_eq!(red.darken(1.0), Color::from_rgb(0, 0, 0));
+  assert_eq!(red.alpha(0.5).darken(0.2).alpha, 0.5);
+}
+
+#[test]
+fn mix() {
+  let red = Color::from_rgb(255, 0, 0);
+  let blue = Color::from_rgb(0, 0, 255);
+
+  assert_eq!(red.mix(&blue, 0.5), Color::from_rgb(128, 0, 128));
+  assert_eq!(red.mix(&blue, 0.25), Color::from_rgb(64, 0, 191));
+  assert_eq!(red.mix(&blue, 1.0), red);
+  assert_eq!(red.mix(&blue.alpha(0.0), 0.5), rgba(255, 0, 0, 0.5));
+}
+
+#[test]
+fn contrast_ratio() {
+  let white = Color::from_rgb(255, 255, 255);
+  let black = Color::from_rgb(0, 0, 0);
+
+  assert_close(black.contrast_ratio(&white), 21.0);
+  assert_close(white.contrast_ratio(&black), 21.0);
+  assert_close(white.contrast_ratio(&white), 1.0);
+  assert_close(parse("#767676").contrast_ratio(&white), 4.54);
+  assert_close(parse("#0db7b6").contrast_ratio(&white), 2.48);
+}
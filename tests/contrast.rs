@@ -0,0 +1,54 @@
+use dao_ui::theme::{contrast_text, generate_palette, Color, Theme, RGB};
+
+const WHITE: Color = Color { alpha: 1.0, rgb: RGB(255, 255, 255) };
+
+#[test]
+fn default_themes_pass_their_own_validation() {
+  assert_eq!(Theme::default().validate(), vec![]);
+  assert_eq!(Theme::dark().validate(), vec![]);
+}
+
+#[test]
+fn contrast_text_picks_the_more_contrasting_color() {
+  assert_eq!(contrast_text(&Color::from_rgb(234, 40, 69)).to_hex(), "#000000");
+  assert_eq!(contrast_text(&Color::from_rgb(244, 67, 54)).to_hex(), "#000000");
+  assert_eq!(contrast_text(&Color::from_rgb(25, 118, 210)).to_hex(), "#ffffff");
+  assert_eq!(contrast_text(&Color::from_rgb(255, 235, 59)).to_hex(), "#000000");
+}
+
+#[test]
+fn text_on_keeps_readable_main() {
+  let color = generate_palette(Color::from_rgb(25, 118, 210));
+
+  assert_eq!(color.text_step(&[WHITE]), None);
+  assert_eq!(color.text_on(&[WHITE]), color.main);
+}
+
+#[test]
+fn text_on_darkens_light_colors_on_light_backgrounds() {
+  let color = generate_palette(Color::from_rgb(13, 183, 182));
+  let step = color.text_step(&[WHITE]).unwrap();
+
+  assert!(step > 500);
+  assert!(Color::parse(color.text_on(&[WHITE])).unwrap().contrast_ratio(&WHITE) >= 4.5);
+}
+
+#[test]
+fn text_on_lightens_dark_colors_on_dark_backgrounds() {
+  let background = Color::from_rgb(30, 30, 30);
+  let color = generate_palette(Color::from_rgb(63, 81, 181));
+
+  assert!(color.text_step(&[background]).unwrap() < 500);
+}
+
+#[test]
+fn validate_reports_low_contrast_text() {
+  let mut theme = Theme::default();
+
+  theme.palette.text_secondary = String::from("#aaaaaa");
+
+  let issues = theme.validate();
+
+  assert_eq!(issues.len(), 2);
+  assert!(issues.iter().all(|issue| issue.foreground == "palette.text_secondary" && issue.ratio < 4.5));
+}
@@ -0,0 +1,41 @@
+use dao_ui::theme::{is_valid_color_name, Color, Theme, ThemeError};
+
+#[test]
+fn color_names() {
+  for name in ["brand", "brand-accent", "accent-2", "primaryish", "texts"] {
+    assert!(is_valid_color_name(name), "{} rejected", name);
+  }
+
+  for name in ["", "Brand", "brand accent", "brand;", "brand)", "a_b", "info", "surface", "text-primary", "primary-contrast"] {
+    assert!(!is_valid_color_name(name), "{} accepted", name);
+  }
+}
+
+#[test]
+fn add_color() {
+  let mut palette = Theme::default().palette;
+
+  palette.add_color("brand", Color::from_rgb(102, 51, 153)).unwrap();
+
+  assert_eq!(palette.color("brand").unwrap().main, "#663399");
+  assert_eq!(
+    palette.add_color("primary", Color::from_rgb(0, 0, 0)),
+    Err(ThemeError::InvalidColorName(String::from("primary"))),
+  );
+  assert_eq!(palette.primary, Theme::default().palette.primary);
+  assert!(palette.add_color("x); color: red", Color::from_rgb(0, 0, 0)).is_err());
+}
+
+#[test]
+fn invalid_custom_names_are_not_written() {
+  let mut theme = Theme::default();
+  let color = theme.palette.primary.clone();
+
+  theme.palette.custom.insert(String::from("brand"), color.clone());
+  theme.palette.custom.insert(String::from("x;}body{color:red"), color);
+
+  let css = theme.to_root_css();
+
+  assert!(css.contains("--dao-palette-brand-main:"));
+  assert!(!css.contains("color:red"));
+}
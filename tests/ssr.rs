@@ -0,0 +1,50 @@
+use dao_ui::ssr::extract_css;
+use dao_ui::theme::{Theme, VARIABLE_PREFIX};
+
+#[test]
+fn extracts_variables_and_component_sheets() {
+  let css = extract_css(&Theme::default());
+  let primary = format!("  {}-palette-primary-main: #0db7b6;", VARIABLE_PREFIX);
+
+  assert!(css.starts_with(":root {\n"));
+  assert!(css.contains(&format!(".dao-theme-provider {{\n  display: contents;\n{}", primary)));
+  assert!(css.contains("@media (prefers-reduced-motion: reduce) {\n.dao-theme-provider {\n"));
+
+  for class in [
+    "dao-button",
+    "dao-button-icon",
+    "dao-button-group",
+    "dao-icon-button",
+    "dao-menu-item",
+    "dao-table",
+    "dao-table-head",
+    "dao-table-body",
+    "dao-table-row",
+    "dao-table-cell",
+    "dao-typography",
+    "dao-spinner",
+  ] {
+    assert!(css.contains(&format!("\n.{} {{", class)), "no sheet for {}", class);
+  }
+
+  assert!(css.contains(".dao-button.loading .content {"));
+  assert_eq!(css.matches("@keyframes").count(), 2);
+  assert!(css.contains("@keyframes dao-spinner-rotate {"));
+  assert!(css.contains("@media (hover: hover) and (pointer: fine) {\n.dao-button.contained:hover"));
+  assert!(!css.contains('&'));
+  assert!(!css.contains('$'));
+}
+
+#[test]
+fn follows_the_theme() {
+  let mut theme = Theme::dark();
+
+  theme.breakpoints.md = 900;
+  theme.components.button.style_overrides = String::from("margin: 0;");
+
+  let css = extract_css(&theme);
+
+  assert!(css.contains(&format!("  {}-palette-background: {};", VARIABLE_PREFIX, theme.palette.background)));
+  assert!(css.contains("@media (min-width: 900px) {\n.dao-menu-item:hover {"));
+  assert!(css.contains(".dao-button.dao-button.dao-button.dao-button.dao-button.dao-button {\nmargin: 0;"));
+}
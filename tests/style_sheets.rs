@@ -0,0 +1,223 @@
+//! Checks the component style sheets: template placeholders must be known,
+//! `var(--dao-*)` references must name a theme token and css-in-rust, the
+//! parser components use at runtime, must read the CSS left after
+//! substitution up to its end.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use css_in_rust::Style;
+use dao_ui::theme::{Breakpoint, Theme, VARIABLE_PREFIX};
+
+/// Properties whose values may hold quoted strings.
+const QUOTED_PROPERTIES: [&str; 3] = ["content", "font-family", "quotes"];
+
+/// A rule added after every sheet. css-in-rust stops at the first rule it
+/// can't read and drops the rest without an error, so the rule only shows
+/// up in the parsed sheet when everything before it was read. It is an
+/// at-rule because css-in-rust can't read a plain rule after `@media`.
+const END_RULE: &str = "@dao-check-end";
+
+/// Placeholders components substitute with `.replace`, with their value in
+/// the default theme.
+fn placeholders() -> Vec<(&'static str, String)> {
+  let breakpoints = Theme::default().breakpoints;
+
+  vec![
+    ("$up_md", breakpoints.up(Breakpoint::Md)),
+    ("$down_md", breakpoints.down(Breakpoint::Md)),
+  ]
+}
+
+fn theme_tokens() -> HashSet<String> {
+  Theme::default().css_variables()
+    .into_iter()
+    .chain(Theme::dark().css_variables())
+    .map(|(name, _)| name)
+    .collect()
+}
+
+fn style_sheets(dir: &Path, sheets: &mut Vec<PathBuf>) {
+  let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+    .unwrap_or_else(|e| panic!("can't read {}: {}", dir.display(), e))
+    .filter_map(|entry| entry.ok().map(|e| e.path()))
+    .collect();
+
+  entries.sort();
+
+  for path in entries {
+    if path.is_dir() {
+      style_sheets(&path, sheets);
+    } else if path.extension().is_some_and(|e| e == "scss") {
+      sheets.push(path);
+    }
+  }
+}
+
+fn is_name_char(c: char) -> bool {
+  c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+fn check_placeholders(css: &str, errors: &mut Vec<String>) {
+  let known = placeholders();
+
+  for (start, _) in css.match_indices('$') {
+    let name: String = css[start + 1..].chars().take_while(|&c| is_name_char(c)).collect();
+    let placeholder = format!("${}", name);
+
+    if !known.iter().any(|(p, _)| *p == placeholder) {
+      errors.push(format!("unknown template placeholder `{}`", placeholder));
+    }
+  }
+}
+
+/// Custom properties the sheets declare themselves, like `--dao-button-color-main`.
+/// Some are declared in a sheet shared by several components.
+fn declared_properties(css: &str) -> Vec<String> {
+  let prefix = format!("{}-", VARIABLE_PREFIX);
+
+  css.lines()
+    .filter_map(|line| line.trim().split_once(':'))
+    .map(|(name, _)| String::from(name))
+    .filter(|name| name.starts_with(&prefix))
+    .collect()
+}
+
+fn check_tokens(css: &str, tokens: &HashSet<String>, errors: &mut Vec<String>) {
+  let prefix = format!("{}-", VARIABLE_PREFIX);
+
+  for (start, _) in css.match_indices("var(") {
+    let name = css[start + 4..]
+      .trim_start()
+      .chars()
+      .take_while(|&c| is_name_char(c))
+      .collect::<String>();
+
+    if name.starts_with(&prefix) && !tokens.contains(&name) {
+      errors.push(format!("unknown theme token `{}`", name));
+    }
+  }
+}
+
+/// Returns the `Debug` strings following every `start` in `parsed`, still
+/// escaped, e.g. the selectors after `condition: Some(`.
+fn parsed_strings<'a>(parsed: &'a str, start: &str) -> Vec<&'a str> {
+  parsed.match_indices(start)
+    .filter_map(|(i, _)| {
+      let rest = parsed[i + start.len()..].strip_prefix('"')?;
+      let mut escaped = false;
+      let end = rest.char_indices().find(|&(_, c)| {
+        let end = c == '"' && !escaped;
+
+        escaped = c == '\\' && !escaped;
+        end
+      })?;
+
+      Some(&rest[..end.0])
+    })
+    .collect()
+}
+
+/// Turns an escaped `Debug` string back into one line of CSS for reports.
+fn unescape(parsed: &str) -> String {
+  parsed.replace("\\n", " ").replace("\\\"", "\"").trim().to_string()
+}
+
+/// Parses `css` with css-in-rust. The parsed sheet is private, its `Debug`
+/// output is the only view of it. `checks_catch_broken_sheets` fails when
+/// a css-in-rust release changes that output.
+fn check_css(css: &str, errors: &mut Vec<String>) {
+  let sheet = format!("{}\n{} {{}}\n", css, END_RULE);
+  let parsed = format!("{:?}", Style::create("check", sheet).expect("css-in-rust never fails natively"));
+
+  if parsed.contains("ast: None") {
+    errors.push(String::from("css-in-rust can't parse the sheet"));
+    return;
+  }
+
+  if !parsed.contains(&format!("condition: \"{} \"", END_RULE)) {
+    let last = parsed_strings(&parsed, "condition: Some(").last().map(|c| unescape(c));
+
+    errors.push(format!(
+      "css-in-rust stops reading the sheet after `{}`",
+      last.unwrap_or_default(),
+    ));
+  }
+
+  // Without an error for it, a stray `}` ends up in the next selector.
+  for condition in parsed_strings(&parsed, "condition: Some(") {
+    if condition.contains('}') {
+      errors.push(format!("stray `}}` before `{}`", unescape(&condition.replace('}', ""))));
+    }
+  }
+
+  let keys = parsed_strings(&parsed, "key: ");
+  let values = parsed_strings(&parsed, "value: ");
+
+  for (key, value) in keys.iter().zip(values) {
+    if value.contains("\\\"") && !QUOTED_PROPERTIES.contains(key) {
+      errors.push(format!("quoted value in `{}: {}`", key, unescape(value)));
+    }
+  }
+}
+
+fn check_sheet(source: &str, tokens: &HashSet<String>) -> Vec<String> {
+  let css = placeholders().iter()
+    .fold(String::from(source), |css, (placeholder, value)| css.replace(placeholder, value));
+  let mut errors = vec![];
+
+  check_placeholders(source, &mut errors);
+  check_tokens(source, tokens, &mut errors);
+  check_css(&css, &mut errors);
+  errors
+}
+
+#[test]
+fn component_sheets_are_valid() {
+  let mut tokens = theme_tokens();
+  let mut paths = vec![];
+  let mut report = vec![];
+
+  style_sheets(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src/components"), &mut paths);
+  assert!(!paths.is_empty());
+
+  let sheets: Vec<(PathBuf, String)> = paths.into_iter()
+    .map(|path| {
+      let source = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("can't read {}: {}", path.display(), e));
+
+      (path, source)
+    })
+    .collect();
+
+  for (_, source) in &sheets {
+    tokens.extend(declared_properties(source));
+  }
+
+  for (path, source) in sheets {
+    for error in check_sheet(&source, &tokens) {
+      report.push(format!("{}: {}", path.display(), error));
+    }
+  }
+
+  assert!(report.is_empty(), "invalid component style sheets:\n{}", report.join("\n"));
+}
+
+#[test]
+fn checks_catch_broken_sheets() {
+  let tokens = theme_tokens();
+  let errors = |css: &str| check_sheet(css, &tokens);
+
+  assert_eq!(errors("& { margin: 0; }\n$up_md { & { margin: 1px; } }"), Vec::<String>::new());
+  assert_eq!(errors("& { margin: $gap; }"), ["unknown template placeholder `$gap`"]);
+  assert_eq!(errors("& { color: var(--dao-nope); }"), ["unknown theme token `--dao-nope`"]);
+  assert_eq!(errors("& { padding: \"1px\"; }"), ["quoted value in `padding: \"1px\"`"]);
+  assert_eq!(errors("& { content: \"a\"; }"), Vec::<String>::new());
+  assert_eq!(errors("& { margin: 0; } }\n&.a { margin: 0; }"), ["stray `}` before `&.a`"]);
+  assert_eq!(errors("& { margin }"), ["css-in-rust can't parse the sheet"]);
+  // css-in-rust can't read a plain rule after `@media`.
+  assert_eq!(
+    errors("$up_md { & { margin: 0; } }\n&.a { margin: 0; }"),
+    ["css-in-rust can't parse the sheet"],
+  );
+}
@@ -0,0 +1,74 @@
+use dao_ui::theme::{generate_palette, Color, Theme, ThemeError};
+
+#[test]
+fn main_only_override_regenerates_palette_color() {
+  let theme = Theme::from_json(r##"{"palette":{"primary":{"main":"#123456"}}}"##).unwrap();
+
+  assert_eq!(theme.palette.primary, generate_palette(Color::from_rgb(0x12, 0x34, 0x56)));
+  assert_eq!(theme.palette.secondary, Theme::default().palette.secondary);
+}
+
+#[test]
+fn fields_next_to_main_win_over_generated_ones() {
+  let theme = Theme::from_toml(r##"
+    [palette.error]
+    main = "#123456"
+    contrast_text = "#ff0000"
+  "##).unwrap();
+  let generated = generate_palette(Color::from_rgb(0x12, 0x34, 0x56));
+
+  assert_eq!(theme.palette.error.contrast_text, "#ff0000");
+  assert_eq!(theme.palette.error.dark, generated.dark);
+  assert_eq!(theme.palette.error.shades, generated.shades);
+}
+
+#[test]
+fn derived_field_override_keeps_other_fields() {
+  let theme = Theme::from_json(r##"{"palette":{"primary":{"dark":"#000000"}}}"##).unwrap();
+  let default = Theme::default().palette.primary;
+
+  assert_eq!(theme.palette.primary.dark, "#000000");
+  assert_eq!(theme.palette.primary.main, default.main);
+  assert_eq!(theme.palette.primary.shades, default.shades);
+}
+
+#[test]
+fn custom_color_can_be_given_by_main_only() {
+  let theme = Theme::from_json(r##"{"palette":{"custom":{"brand":{"main":"rebeccapurple"}}}}"##).unwrap();
+
+  let brand = &theme.palette.custom["brand"];
+  let generated = generate_palette(Color::from_rgb(102, 51, 153));
+
+  assert_eq!(brand.main, "rebeccapurple");
+  assert_eq!(brand.contrast_text, generated.contrast_text);
+  assert_eq!(brand.shades, generated.shades);
+}
+
+#[test]
+fn invalid_main_is_reported() {
+  let error = Theme::from_json(r##"{"palette":{"primary":{"main":"#12345"}}}"##).unwrap_err();
+
+  assert_eq!(error, ThemeError::InvalidColor {
+    path: String::from("palette.primary.main"),
+    value: String::from("#12345"),
+  });
+}
+
+#[test]
+fn invalid_custom_color_names_are_reported() {
+  for name in ["primary", "primary-contrast", "Brand", "brand;color:red", "a b", ""] {
+    let source = format!(r##"{{"palette":{{"custom":{{"{}":{{"main":"#123456"}}}}}}}}"##, name);
+
+    assert_eq!(Theme::from_json(&source).unwrap_err(), ThemeError::InvalidColorName(String::from(name)));
+  }
+}
+
+#[test]
+fn spacing_unit_must_be_a_non_negative_number() {
+  assert_eq!(Theme::from_toml("spacing_unit = 4.0").unwrap().spacing_unit, 4.0);
+  assert_eq!(Theme::from_json(r#"{"spacing_unit":0}"#).unwrap().spacing_unit, 0.0);
+
+  for source in ["spacing_unit = nan", "spacing_unit = inf", "spacing_unit = -inf", "spacing_unit = -8.0"] {
+    assert!(matches!(Theme::from_toml(source), Err(ThemeError::Parse(_))), "{} accepted", source);
+  }
+}